@@ -0,0 +1,61 @@
+//! Regression checks against real puzzle answers.
+//!
+//! Every teammate has their own puzzle input, so real answers live in a
+//! gitignored `answers.toml` in the workspace root, next to the gitignored
+//! inputs they belong to:
+//!
+//! ```toml
+//! [day12]
+//! part1 = 412
+//! part2 = 402
+//! ```
+//!
+//! Days without an entry, or without an input, are skipped.
+
+use crate::{input, solve, Solution};
+use std::path::PathBuf;
+
+pub fn path() -> PathBuf {
+    input::workspace_root().join("answers.toml")
+}
+
+/// The recorded answers for `day`, indexed by part.
+fn load(day: u8) -> Option<[Option<String>; 2]> {
+    let s = std::fs::read_to_string(path()).ok()?;
+    let table: toml::Table = s
+        .parse()
+        .unwrap_or_else(|err| panic!("invalid {}: {}", path().display(), err));
+    let day = table.get(&format!("day{:02}", day))?.as_table()?;
+    let answer = |part: &str| {
+        day.get(part).map(|value| match value {
+            toml::Value::String(s) => s.clone(),
+            value => value.to_string(),
+        })
+    };
+    Some([answer("part1"), answer("part2")])
+}
+
+/// Solves `S` on the real input and asserts that every answer recorded in
+/// `answers.toml` still comes out the same.
+pub fn check<S: Solution>() {
+    let Some(expected) = load(S::DAY) else {
+        return;
+    };
+    let Ok(s) = input::read(S::DAY, None) else {
+        eprintln!("day {}: no input, skipping recorded answers", S::DAY);
+        return;
+    };
+
+    let report = solve::<S>(&s, None).unwrap_or_else(|err| panic!("{}", err.diagnostic()));
+    for answer in report.answers {
+        if let Some(expected) = &expected[answer.part as usize - 1] {
+            assert_eq!(
+                &answer.answer,
+                expected,
+                "day {} part {} differs from answers.toml",
+                S::DAY,
+                answer.part
+            );
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use crate::ParseError;
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid, as `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// Up, left, right and down, as `(row, column)` steps.
+pub const DIRECTIONS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The four directions of [`DIRECTIONS4`] and the four diagonals.
+pub const DIRECTIONS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A dense 2D grid stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses a map with one character per cell, mapping each character with
+    /// `f`. Characters `f` does not accept are reported as `expected`.
+    pub fn parse(
+        s: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = s.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(s.len());
+        let mut height = 0;
+        for line in s.lines() {
+            let mut len = 0;
+            for (i, c) in line.char_indices() {
+                let cell =
+                    f(c).ok_or_else(|| ParseError::new(expected, &line[i..i + c.len_utf8()]))?;
+                cells.push(cell);
+                len += 1;
+            }
+            if len != width {
+                return Err(ParseError::new(format!("a row of {} cells", width), line));
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if pos.0 < self.height && pos.1 < self.width {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if pos.0 < self.height && pos.1 < self.width {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// `pos` moved one `step`, if that is still inside the grid.
+    pub fn step(&self, pos: Pos, step: (isize, isize)) -> Option<Pos> {
+        let row = pos.0.checked_add_signed(step.0)?;
+        let col = pos.1.checked_add_signed(step.1)?;
+        (row < self.height && col < self.width).then_some((row, col))
+    }
+
+    /// The up to four orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS4
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    /// The up to eight neighbours of `pos` inside the grid, diagonals
+    /// included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS8
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    /// The cells from `pos` (exclusive) to the edge of the grid, walking in
+    /// `direction`.
+    pub fn ray(&self, pos: Pos, direction: (isize, isize)) -> impl Iterator<Item = (Pos, &T)> {
+        std::iter::successors(self.step(pos, direction), move |&pos| {
+            self.step(pos, direction)
+        })
+        .map(|pos| (pos, &self[pos]))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / width, i % width), cell))
+    }
+
+    /// The position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i / self.width, i % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders the grid back to text, one character per cell.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            s.extend(row.iter().map(&mut f));
+            s.push('\n');
+        }
+        s
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn from_elem(height: usize, width: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The position of the first cell, row by row, equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Pos> {
+        self.position(|cell| cell == value)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(
+            pos.0 < self.height && pos.1 < self.width,
+            "{:?} out of bounds",
+            pos
+        );
+        &self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(
+            pos.0 < self.height && pos.1 < self.width,
+            "{:?} out of bounds",
+            pos
+        );
+        &mut self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u32> {
+        Grid::parse("123\n456", "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse_and_render() {
+        let grid = example();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(
+            grid.render(|&v| char::from_digit(v, 10).unwrap()),
+            "123\n456\n"
+        );
+
+        let err = Grid::parse("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.found(), "x");
+        let err = Grid::parse("12\n345", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.expected(), "a row of 2 cells");
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = example();
+        let corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rays_and_lines() {
+        let grid = example();
+        let left: Vec<_> = grid.ray((1, 2), (0, -1)).map(|(_, &v)| v).collect();
+        assert_eq!(left, [5, 4]);
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.find(&5), Some((1, 1)));
+        assert_eq!(grid.find(&7), None);
+    }
+}
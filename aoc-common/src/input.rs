@@ -0,0 +1,66 @@
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+pub(crate) fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// Where a day's input is read from when no `--input` is given:
+/// `aocNN/src/input.txt` in the workspace.
+pub fn default_path(day: u8) -> PathBuf {
+    workspace_root()
+        .join(format!("aoc{:02}", day))
+        .join("src")
+        .join("input.txt")
+}
+
+/// Reads the puzzle input for `day` from `path`, from stdin when `path` is
+/// `-`, or from the day's default path when no path is given.
+///
+/// The trailing newline is not part of the puzzle and is dropped.
+pub fn read(day: u8, path: Option<&Path>) -> Result<String, String> {
+    let mut s = match path {
+        Some(path) if path == Path::new("-") => {
+            let mut s = String::new();
+            std::io::stdin()
+                .read_to_string(&mut s)
+                .map_err(|err| format!("cannot read input from stdin: {}", err))?;
+            s
+        }
+        path => {
+            let path = path.map_or_else(|| default_path(day), Path::to_owned);
+            std::fs::read_to_string(&path)
+                .map_err(|err| format!("cannot read input {}: {}", path.display(), err))?
+        }
+    };
+    s.truncate(s.trim_end_matches('\n').len());
+    Ok(s)
+}
+
+/// Where a day's input comes from, for reading it only once it is known how.
+#[derive(Clone, Debug)]
+pub struct Source {
+    pub day: u8,
+    /// As for [`read`].
+    pub path: Option<PathBuf>,
+}
+
+impl Source {
+    /// The whole input, as [`read`] gives it.
+    pub fn read(&self) -> Result<String, String> {
+        read(self.day, self.path.as_deref())
+    }
+
+    /// The input for reading a bit at a time, without holding all of it.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, String> {
+        match self.path.as_deref() {
+            Some(path) if path == Path::new("-") => Ok(Box::new(std::io::stdin().lock())),
+            path => {
+                let path = path.map_or_else(|| default_path(self.day), Path::to_owned);
+                let file = std::fs::File::open(&path)
+                    .map_err(|err| format!("cannot read input {}: {}", path.display(), err))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
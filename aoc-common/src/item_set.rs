@@ -0,0 +1,197 @@
+//! Sets of ASCII letters in a single machine word.
+//!
+//! Each letter has a priority, `a`-`z` being 1 to 26 and `A`-`Z` 27 to 52,
+//! and is kept as that bit of a `u64`, so building, intersecting and
+//! counting sets never allocates and takes a handful of instructions.
+
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+/// The priority of `item`, or `None` when it is not an ASCII letter.
+pub fn priority(item: u8) -> Option<u8> {
+    match item {
+        b'a'..=b'z' => Some(item - b'a' + 1),
+        b'A'..=b'Z' => Some(item - b'A' + 27),
+        _ => None,
+    }
+}
+
+/// The letter with priority `priority`, the inverse of [`priority`].
+fn item(priority: u8) -> u8 {
+    match priority {
+        1..=26 => b'a' + priority - 1,
+        _ => b'A' + priority - 27,
+    }
+}
+
+/// A set of ASCII letters.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// The set of the letters in `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// If any of `bytes` is not an ASCII letter.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        bytes.iter().copied().collect()
+    }
+
+    /// Adds `item`, returning whether it was new.
+    ///
+    /// # Panics
+    ///
+    /// If `item` is not an ASCII letter.
+    pub fn insert(&mut self, item: u8) -> bool {
+        let bit = Self::bit(item);
+        let new = self.0 & bit == 0;
+        self.0 |= bit;
+        new
+    }
+
+    /// Whether the set holds `item`; never for anything but a letter.
+    pub fn contains(&self, item: u8) -> bool {
+        priority(item).is_some_and(|priority| self.0 & 1 << priority != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The letters in the set by priority, `a` first and `Z` last.
+    pub fn iter(&self) -> Iter {
+        Iter(self.0)
+    }
+
+    fn bit(item: u8) -> u64 {
+        let priority =
+            priority(item).unwrap_or_else(|| panic!("{:?} is not an ASCII letter", item as char));
+        1 << priority
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.iter().map(|item| item as char))
+            .finish()
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    /// The letters in both sets.
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl BitAndAssign for ItemSet {
+    fn bitand_assign(&mut self, other: Self) {
+        self.0 &= other.0;
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = Self;
+
+    /// The letters in either set.
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for ItemSet {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl FromIterator<u8> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<u8> for ItemSet {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for item in iter {
+            self.0 |= Self::bit(item);
+        }
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = u8;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+/// The letters of an [`ItemSet`], by priority.
+#[derive(Clone, Debug)]
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.0 == 0 {
+            return None;
+        }
+        let priority = self.0.trailing_zeros() as u8;
+        // Clear the lowest bit.
+        self.0 &= self.0 - 1;
+        Some(item(priority))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let one = ItemSet::from_bytes(b"vJrwpWtwJgWr");
+        let two = ItemSet::from_bytes(b"hcsFMMfFFhFp");
+        assert_eq!((one & two).iter().collect::<Vec<_>>(), b"p");
+        assert_eq!(one.len(), 8);
+        assert_eq!((one | two).len(), 8 + 7 - 1);
+        assert!(one.contains(b'J') && !one.contains(b'j') && !one.contains(b'!'));
+        assert!((one & ItemSet::new()).is_empty());
+    }
+
+    #[test]
+    fn priorities_round_trip() {
+        let all: ItemSet = (b'a'..=b'z').chain(b'A'..=b'Z').collect();
+        assert_eq!(all.len(), 52);
+        for (i, item) in all.into_iter().enumerate() {
+            assert_eq!(priority(item), Some(i as u8 + 1));
+        }
+        let mut set = ItemSet::new();
+        assert!(set.insert(b'Z'));
+        assert!(!set.insert(b'Z'));
+        assert_eq!(format!("{:?}", set), "{'Z'}");
+    }
+}
@@ -0,0 +1,273 @@
+use clap::Parser;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use verify::VerifyArgs;
+use visualize::export::{self, Recorder, Survey};
+use visualize::terminal::Terminal;
+use visualize::{Painter, Sink, VisualizeArgs};
+
+pub mod answers;
+pub mod grid;
+pub mod input;
+pub mod item_set;
+pub mod parse;
+pub mod search;
+pub mod top_k;
+pub mod verify;
+pub mod visualize;
+pub mod voxel;
+
+pub use parse::ParseError;
+
+/// A single day's puzzle: how to turn the raw input into `Input`, and how to
+/// solve both parts from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    fn part2(input: &Self::Input) -> impl Display;
+
+    /// Replays `part` as an animation, emitting a frame to `sink` for every
+    /// step worth showing. Most days have nothing to show.
+    fn visualize(input: &Self::Input, part: u8, sink: &mut dyn Sink) {
+        let _ = (input, part, sink);
+    }
+}
+
+/// The answer to one part of a puzzle and the time it took to compute.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// Everything produced by solving a day: the parse time and one answer per
+/// part that was run.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let now = Instant::now();
+    let value = f();
+    (value, now.elapsed())
+}
+
+/// Parses `s` and solves `part` of `S`, or both parts when `part` is `None`.
+pub fn solve<S: Solution>(s: &str, part: Option<u8>) -> Result<Report, ParseError> {
+    let (input, parse) = timed(|| S::parse(s));
+    let input = input.map_err(|err| err.locate(s))?;
+    Ok(Report {
+        parse,
+        answers: answer::<S>(&input, part),
+    })
+}
+
+/// Solves `part` of `S` from its parsed input, or both parts when `part` is
+/// `None`.
+pub fn answer<S: Solution>(input: &S::Input, part: Option<u8>) -> Vec<Answer> {
+    parts(part)
+        .map(|part| {
+            let (answer, elapsed) = match part {
+                1 => timed(|| S::part1(input).to_string()),
+                _ => timed(|| S::part2(input).to_string()),
+            };
+            Answer {
+                part,
+                answer,
+                elapsed,
+            }
+        })
+        .collect()
+}
+
+/// Options shared by every way of running a solution.
+#[derive(clap::Args, Clone, Debug)]
+pub struct Args {
+    /// Read the puzzle input from this file, or from stdin when `-`
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+
+    /// Only solve this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+}
+
+/// No options beyond the common ones.
+#[derive(clap::Args, Clone, Debug)]
+pub struct NoOptions {}
+
+#[derive(Parser)]
+struct Cli<A: clap::Args> {
+    #[command(flatten)]
+    args: Args,
+
+    #[command(flatten)]
+    options: A,
+
+    #[command(flatten)]
+    verify: VerifyArgs,
+
+    #[command(flatten)]
+    visualize: VisualizeArgs,
+}
+
+fn exit_with(err: impl Display) -> ! {
+    eprintln!("error: {}", err);
+    std::process::exit(1);
+}
+
+/// The parts to run: `part`, or both parts when `part` is `None`.
+fn parts(part: Option<u8>) -> impl Iterator<Item = u8> + Clone {
+    [1, 2]
+        .into_iter()
+        .filter(move |&p| part.is_none_or(|part| part == p))
+}
+
+/// Plays the selected parts of `S` in the terminal.
+fn animate<S: Solution>(
+    input: &S::Input,
+    part: Option<u8>,
+    args: &VisualizeArgs,
+) -> Result<(), String> {
+    let palette = args.palette.clone().unwrap_or_default();
+    let mut terminal = Terminal::new(args.fps);
+    for part in parts(part) {
+        terminal.set_title(format!("day {} part {}", S::DAY, part));
+        let mut painter = Painter {
+            palette: &palette,
+            sink: &mut terminal,
+        };
+        S::visualize(input, part, &mut painter);
+        terminal.hold();
+        if terminal.quit() {
+            break;
+        }
+    }
+    let shown = terminal.shown();
+    terminal
+        .close()
+        .map_err(|err| format!("cannot animate in the terminal: {}", err))?;
+    if shown == 0 {
+        eprintln!("day {} has no visualization", S::DAY);
+    }
+    Ok(())
+}
+
+/// Records the selected parts of `S`, one after the other, to `path`.
+fn record<S: Solution>(
+    input: &S::Input,
+    part: Option<u8>,
+    path: &Path,
+    args: &VisualizeArgs,
+) -> Result<(), String> {
+    let mut survey = Survey::default();
+    for part in parts(part) {
+        S::visualize(input, part, &mut survey);
+    }
+    if survey.frames == 0 {
+        return Err(format!("day {} has no visualization", S::DAY));
+    }
+
+    let palette = args.palette.clone().unwrap_or_default();
+    let options = export::Options {
+        fps: args.fps,
+        scale: args.scale,
+        every: args.every,
+        background: palette.background(),
+    };
+    let mut recorder = Recorder::create(path, &survey, options)?;
+    for part in parts(part) {
+        let mut painter = Painter {
+            palette: &palette,
+            sink: &mut recorder,
+        };
+        S::visualize(input, part, &mut painter);
+    }
+    let written = recorder.finish()?;
+    eprintln!("recorded {} frames to {}", written, path.display());
+    Ok(())
+}
+
+/// Entry point for a day's binary: reads the input selected on the command
+/// line, then parses and solves `S`, printing each answer along with the
+/// time it took, and with its verdict when verifying. With `--visualize`
+/// or `--record`, the solution is animated first.
+pub fn main<S: Solution>() {
+    main_with::<S, NoOptions>(|_, _, _| None);
+}
+
+/// Parses `s` as the input of `S`, describing any error with its line and
+/// column.
+pub fn parse_input<S: Solution>(s: &str) -> Result<S::Input, String> {
+    S::parse(s).map_err(|err| err.locate(s).diagnostic())
+}
+
+/// Like [`main`], for a day with extra command line options `A`. When
+/// `report` makes something of them and the input, that is printed instead
+/// of the answers, or reported as an error. The input is left for `report`
+/// to read, as options may change how it should be read and parsed.
+pub fn main_with<S: Solution, A: clap::Args>(
+    report: impl FnOnce(&A, &Args, &input::Source) -> Option<Result<String, String>>,
+) {
+    let Cli {
+        args,
+        options,
+        verify,
+        visualize,
+    } = Cli::<A>::parse();
+    let source = input::Source {
+        day: S::DAY,
+        path: args.input.clone(),
+    };
+    if let Some(report) = report(&options, &args, &source) {
+        print!("{}", report.unwrap_or_else(|err| exit_with(err)));
+        return;
+    }
+    let s = source.read().unwrap_or_else(|err| exit_with(err));
+    let (input, parse) = timed(|| parse_input::<S>(&s));
+    let input = input.unwrap_or_else(|err| exit_with(err));
+    if visualize.visualize {
+        animate::<S>(&input, args.part, &visualize).unwrap_or_else(|err| exit_with(err));
+    }
+    if let Some(path) = &visualize.record {
+        record::<S>(&input, args.part, path, &visualize).unwrap_or_else(|err| exit_with(err));
+    }
+    let report = Report {
+        parse,
+        answers: answer::<S>(&input, args.part),
+    };
+    let verdicts = if verify.enabled() {
+        verify::judge(S::DAY, &s, &report.answers, &verify).unwrap_or_else(|err| exit_with(err))
+    } else {
+        Vec::new()
+    };
+
+    println!("parse: {:.2?}", report.parse);
+    for (i, answer) in report.answers.iter().enumerate() {
+        let verdict = verdicts
+            .get(i)
+            .map(|verdict| format!(" {}", verdict))
+            .unwrap_or_default();
+        if answer.answer.contains('\n') {
+            println!(
+                "part{} ({:.2?}){}:\n{}",
+                answer.part, answer.elapsed, verdict, answer.answer
+            );
+        } else {
+            println!(
+                "part{}: {} ({:.2?}){}",
+                answer.part, answer.answer, answer.elapsed, verdict
+            );
+        }
+    }
+}
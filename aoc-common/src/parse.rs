@@ -0,0 +1,214 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// An error produced while parsing puzzle input.
+///
+/// Parsers only ever see slices of the original input, so the error records
+/// where the offending token lives in memory. Once the whole input has been
+/// parsed, [`ParseError::locate`] turns that into a line and column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    expected: String,
+    found: String,
+    span: usize,
+    line: usize,
+    column: usize,
+    source_line: String,
+}
+
+impl ParseError {
+    /// `found` must be a slice of the text being parsed, so that the error
+    /// can later be located within it.
+    pub fn new(expected: impl Into<String>, found: &str) -> Self {
+        Self {
+            expected: expected.into(),
+            found: found.to_string(),
+            span: found.as_ptr() as usize,
+            line: 0,
+            column: 0,
+            source_line: String::new(),
+        }
+    }
+
+    /// An error pointing just past the end of `s`, for when more input was
+    /// expected.
+    pub fn end(expected: impl Into<String>, s: &str) -> Self {
+        Self::new(expected, &s[s.len()..])
+    }
+
+    /// Resolves the line and column of the offending token within `source`,
+    /// the complete text that was handed to the parser.
+    pub fn locate(mut self, source: &str) -> Self {
+        let start = source.as_ptr() as usize;
+        let Some(offset) = self.span.checked_sub(start) else {
+            return self;
+        };
+        if offset > source.len() {
+            return self;
+        }
+
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+        self.line = source[..offset].matches('\n').count() + 1;
+        self.column = source[line_start..offset].chars().count() + 1;
+        self.source_line = source[line_start..line_end].to_string();
+        self
+    }
+
+    /// Like [`ParseError::locate`], for input read one line at a time, where
+    /// `line` is the `number`th line, counting from 1.
+    pub fn locate_line(self, line: &str, number: usize) -> Self {
+        let mut err = self.locate(line);
+        if err.line != 0 {
+            err.line = number;
+        }
+        err
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    /// The 1-based line of the offending token, or 0 if it has not been
+    /// located.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The 1-based column of the offending token, or 0 if it has not been
+    /// located.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Renders the error together with the offending source line, with the
+    /// token underlined.
+    pub fn diagnostic(&self) -> String {
+        if self.line == 0 {
+            return self.to_string();
+        }
+
+        let gutter = self.line.to_string().len();
+        let underline = self.found.lines().next().unwrap_or("").chars().count();
+        format!(
+            "{}\n{:gutter$} |\n{} | {}\n{:gutter$} | {:column$}{}",
+            self,
+            "",
+            self.line,
+            self.source_line,
+            "",
+            "",
+            "^".repeat(underline.max(1)),
+            column = self.column - 1,
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line != 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+        write!(f, "expected {}, found ", self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "`{}`", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The first `len` bytes of `s`, shortened to a char boundary.
+fn head(s: &str, len: usize) -> &str {
+    let mut len = len.min(s.len());
+    while !s.is_char_boundary(len) {
+        len -= 1;
+    }
+    &s[..len]
+}
+
+/// Strips `prefix` from `s`.
+pub fn prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(format!("`{}`", prefix), head(s, prefix.len())))
+}
+
+/// Strips `suffix` from `s`.
+pub fn suffix<'a>(s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    s.strip_suffix(suffix).ok_or_else(|| {
+        let mut start = s.len().saturating_sub(suffix.len());
+        while !s.is_char_boundary(start) {
+            start += 1;
+        }
+        ParseError::new(format!("`{}`", suffix), &s[start..])
+    })
+}
+
+/// Splits `s` around the first occurrence of `delimiter`.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(format!("`{}`", delimiter), s))
+}
+
+/// Parses `s` as a number.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new("a number", s))
+}
+
+/// Takes the next item from `iter`, where `s` is the text being iterated
+/// over.
+pub fn next<'a>(
+    iter: &mut impl Iterator<Item = &'a str>,
+    expected: &str,
+    s: &str,
+) -> Result<&'a str, ParseError> {
+    iter.next().ok_or_else(|| ParseError::end(expected, s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_token() {
+        let s = "move 1 from 2 to 1\nmove x from 1 to 3";
+        let line = s.lines().nth(1).unwrap();
+        let err = number::<usize>(&line[5..6]).unwrap_err().locate(s);
+        assert_eq!((err.line(), err.column()), (2, 6));
+        assert_eq!(
+            err.diagnostic(),
+            "line 2, column 6: expected a number, found `x`\n  |\n2 | move x from 1 to 3\n  |      ^"
+        );
+    }
+
+    #[test]
+    fn locate_end() {
+        let s = "Monkey 0:\n  Starting items: 79";
+        let err = ParseError::end("`Operation:`", s).locate(s);
+        assert_eq!((err.line(), err.column()), (2, 21));
+        assert_eq!(err.found(), "");
+    }
+
+    #[test]
+    fn locate_streamed_line() {
+        let line = "12x4".to_string();
+        let err = number::<u32>(&line).unwrap_err().locate_line(&line, 7);
+        assert_eq!((err.line(), err.column()), (7, 1));
+    }
+
+    #[test]
+    fn unrelated_text_is_not_located() {
+        let text = "3-4\n1,2";
+        let err = split_once(&text[..3], ",").unwrap_err().locate(&text[4..]);
+        assert_eq!(err.line(), 0);
+        assert_eq!(err.to_string(), "expected `,`, found `3-4`");
+    }
+}
@@ -0,0 +1,195 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! Nodes are only ever reached through a `neighbours` function, so the graph
+//! can be a grid, an adjacency map, or a state space generated on the fly.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A shortest path and its total cost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// Every node on the path, from the start up to and including the goal.
+    pub nodes: Vec<N>,
+}
+
+fn reconstruct<N: Eq + Hash + Clone>(parents: &HashMap<N, N>, goal: N) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(parent) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+/// Breadth-first search for the fewest steps from `start` to a node for
+/// which `is_goal` holds.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::from([(start.clone(), 0)]);
+    while let Some((node, cost)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(&parents, node),
+            });
+        }
+        for next in neighbours(&node) {
+            if next != start {
+                if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                    entry.insert(node.clone());
+                    queue.push_back((next, cost + 1));
+                }
+            }
+        }
+    }
+    None
+}
+
+struct State<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // min heap on the estimate, preferring the deepest node on ties
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+/// Dijkstra's algorithm for the cheapest path from `start` to a node for
+/// which `is_goal` holds. `neighbours` yields each next node along with the
+/// cost of moving there, and costs start from `C::default()`.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search, which is [`dijkstra`] guided by a `heuristic` that must never
+/// overestimate the remaining cost to a goal.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut heap = BinaryHeap::from([State {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+
+    while let Some(State { cost, node, .. }) = heap.pop() {
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(&parents, node),
+            });
+        }
+        if cost > costs[&node] {
+            continue;
+        }
+
+        for (next, step) in neighbours(&node) {
+            let cost = cost + step;
+            if costs.get(&next).is_some_and(|&known| known <= cost) {
+                continue;
+            }
+            costs.insert(next.clone(), cost);
+            parents.insert(next.clone(), node.clone());
+            heap.push(State {
+                estimate: cost + heuristic(&next),
+                cost,
+                node: next,
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 2 -1-> 3, plus a costly shortcut 0 -5-> 3
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1), (0, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let path = bfs(0, |n| edges(n).into_iter().map(|(n, _)| n), |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, [0, 3]);
+        assert_eq!(
+            bfs(0, |n| edges(n).into_iter().map(|(n, _)| n), |&n| n == 4),
+            None
+        );
+    }
+
+    #[test]
+    fn dijkstra_weighs_edges() {
+        let path = dijkstra(0, edges, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, [0, 1, 2, 3]);
+        assert_eq!(dijkstra(0, edges, |&n| n == 0).unwrap().nodes, [0]);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let path = astar(0, edges, |&n| 3 - n.min(3), |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, [0, 1, 2, 3]);
+    }
+}
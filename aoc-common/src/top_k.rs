@@ -0,0 +1,114 @@
+//! Picking the largest few items out of a stream.
+//!
+//! Only the best `k` items seen so far are kept, in a min-heap whose root is
+//! the one to evict next, so selecting from `n` items takes `O(n log k)`
+//! time and `O(k)` space however long the stream is.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+struct Ranked<K, T> {
+    key: K,
+    /// Where the item came in the stream, to keep the earlier of equal keys.
+    seq: usize,
+    item: T,
+}
+
+impl<K: Ord, T> Ord for Ranked<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key
+            .cmp(&other.key)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<K: Ord, T> PartialOrd for Ranked<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> PartialEq for Ranked<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: Ord, T> Eq for Ranked<K, T> {}
+
+/// The `k` items of `items` with the largest keys, largest first.
+fn select<K: Ord, T>(items: impl Iterator<Item = (K, T)>, k: usize) -> Vec<(K, T)> {
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut heap = BinaryHeap::with_capacity(k);
+    for (seq, (key, item)) in items.enumerate() {
+        let ranked = Reverse(Ranked { key, seq, item });
+        if heap.len() < k {
+            heap.push(ranked);
+        } else if let Some(mut lowest) = heap.peek_mut() {
+            if ranked < *lowest {
+                *lowest = ranked;
+            }
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(ranked)| (ranked.key, ranked.item))
+        .collect()
+}
+
+/// Streaming top-K selection for any iterator.
+pub trait TopK: Iterator + Sized {
+    /// The `k` largest items, largest first. Equal items keep the order they
+    /// came in.
+    fn top_k(self, k: usize) -> std::vec::IntoIter<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        let top: Vec<_> = select(self.map(|item| (item, ())), k)
+            .into_iter()
+            .map(|(item, ())| item)
+            .collect();
+        top.into_iter()
+    }
+
+    /// The `k` items with the largest keys, largest first. Items with equal
+    /// keys keep the order they came in.
+    fn top_k_by_key<K: Ord>(
+        self,
+        k: usize,
+        mut key: impl FnMut(&Self::Item) -> K,
+    ) -> std::vec::IntoIter<Self::Item> {
+        let top: Vec<_> = select(self.map(|item| (key(&item), item)), k)
+            .into_iter()
+            .map(|(_, item)| item)
+            .collect();
+        top.into_iter()
+    }
+}
+
+impl<I: Iterator> TopK for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn largest_first() {
+        let top: Vec<_> = [5, 1, 9, 3, 7, 9, 2].into_iter().top_k(3).collect();
+        assert_eq!(top, [9, 9, 7]);
+        assert_eq!([4, 2].into_iter().top_k(5).collect::<Vec<_>>(), [4, 2]);
+        assert_eq!([4, 2].into_iter().top_k(0).count(), 0);
+    }
+
+    #[test]
+    fn ties_keep_their_order() {
+        let words = ["bb", "a", "cc", "dd", "e"];
+        let top: Vec<_> = words
+            .into_iter()
+            .top_k_by_key(2, |word| word.len())
+            .collect();
+        assert_eq!(top, ["bb", "cc"]);
+    }
+}
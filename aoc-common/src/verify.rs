@@ -0,0 +1,276 @@
+//! A local store of known answers, so refactors can be checked without
+//! submitting anything.
+//!
+//! Answers are recorded per day and per input, keyed by a hash of the input,
+//! in a gitignored `verify.json` in the workspace root. A part's answer is
+//! either accepted as correct, or rejected as a wrong guess, optionally with
+//! the too-high/too-low hint the puzzle gave for it.
+
+use crate::{input, Answer};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// The hint given for a wrong numeric answer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong(Option<Hint>),
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "CORRECT"),
+            Verdict::Wrong(None) => write!(f, "WRONG"),
+            Verdict::Wrong(Some(hint)) => write!(f, "WRONG ({})", hint),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Guess {
+    answer: String,
+    hint: Option<Hint>,
+}
+
+/// What is known about the answer to one part for one input.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    wrong: Vec<Guess>,
+}
+
+fn hint_from(answer: &str, known: &str) -> Option<Hint> {
+    let answer: i128 = answer.parse().ok()?;
+    let known: i128 = known.parse().ok()?;
+    Some(if answer > known {
+        Hint::TooHigh
+    } else {
+        Hint::TooLow
+    })
+}
+
+impl Record {
+    pub fn accept(&mut self, answer: &str) {
+        self.wrong.retain(|guess| guess.answer != answer);
+        self.correct = Some(answer.to_string());
+    }
+
+    pub fn reject(&mut self, answer: &str, hint: Option<Hint>) {
+        if self.correct.as_deref() == Some(answer) {
+            self.correct = None;
+        }
+        self.wrong.retain(|guess| guess.answer != answer);
+        self.wrong.push(Guess {
+            answer: answer.to_string(),
+            hint,
+        });
+    }
+
+    /// Judges `answer` against the correct answer if one is known, and
+    /// otherwise against the wrong guesses and the bounds their hints give.
+    pub fn verdict(&self, answer: &str) -> Verdict {
+        if let Some(correct) = &self.correct {
+            return if correct == answer {
+                Verdict::Correct
+            } else {
+                Verdict::Wrong(hint_from(answer, correct))
+            };
+        }
+
+        if let Some(guess) = self.wrong.iter().find(|guess| guess.answer == answer) {
+            return Verdict::Wrong(guess.hint);
+        }
+        for guess in &self.wrong {
+            match (guess.hint, hint_from(answer, &guess.answer)) {
+                (Some(Hint::TooHigh), Some(Hint::TooHigh)) => {
+                    return Verdict::Wrong(Some(Hint::TooHigh))
+                }
+                (Some(Hint::TooLow), Some(Hint::TooLow)) => {
+                    return Verdict::Wrong(Some(Hint::TooLow))
+                }
+                _ => {}
+            }
+        }
+        Verdict::Unknown
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct Entry {
+    #[serde(default)]
+    part1: Record,
+    #[serde(default)]
+    part2: Record,
+}
+
+impl Entry {
+    fn part_mut(&mut self, part: u8) -> &mut Record {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+/// Every recorded answer, by day and then by input hash.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Store {
+    days: BTreeMap<String, BTreeMap<String, Entry>>,
+}
+
+/// A stable 64-bit FNV-1a hash of the input, so the key does not change
+/// between compiler versions.
+pub fn input_hash(s: &str) -> String {
+    let hash = s.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+impl Store {
+    pub fn path() -> PathBuf {
+        input::workspace_root().join("verify.json")
+    }
+
+    pub fn load() -> Result<Self, String> {
+        let path = Self::path();
+        match std::fs::read_to_string(&path) {
+            Ok(s) => serde_json::from_str(&s)
+                .map_err(|err| format!("invalid {}: {}", path.display(), err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("cannot read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path();
+        let s = serde_json::to_string_pretty(self).unwrap();
+        std::fs::write(&path, s + "\n")
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+
+    pub fn record(&mut self, day: u8, input: &str, part: u8) -> &mut Record {
+        self.days
+            .entry(format!("day{:02}", day))
+            .or_default()
+            .entry(input_hash(input))
+            .or_default()
+            .part_mut(part)
+    }
+}
+
+/// Options for checking answers against the store, and for recording them.
+#[derive(clap::Args, Clone, Debug)]
+pub struct VerifyArgs {
+    /// Check the answers against the local answer store
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Record the answers as correct
+    #[arg(long, conflicts_with = "reject")]
+    pub accept: bool,
+
+    /// Record the answer to `--part` as a wrong guess
+    #[arg(long, requires = "part")]
+    pub reject: bool,
+
+    /// The hint given for the rejected answer
+    #[arg(long, requires = "reject")]
+    pub hint: Option<Hint>,
+}
+
+impl VerifyArgs {
+    pub fn enabled(&self) -> bool {
+        self.verify || self.accept || self.reject
+    }
+}
+
+/// Records the answers as requested by `args`, then judges each of them.
+pub fn judge(
+    day: u8,
+    input: &str,
+    answers: &[Answer],
+    args: &VerifyArgs,
+) -> Result<Vec<Verdict>, String> {
+    let mut store = Store::load()?;
+    if args.accept || args.reject {
+        for answer in answers {
+            let record = store.record(day, input, answer.part);
+            if args.accept {
+                record.accept(&answer.answer);
+            } else {
+                record.reject(&answer.answer, args.hint);
+            }
+        }
+        store.save()?;
+    }
+
+    let verdicts = answers
+        .iter()
+        .map(|answer| {
+            store
+                .record(day, input, answer.part)
+                .verdict(&answer.answer)
+        })
+        .collect();
+    Ok(verdicts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_answer() {
+        let mut record = Record::default();
+        assert_eq!(record.verdict("42"), Verdict::Unknown);
+        record.accept("42");
+        assert_eq!(record.verdict("42"), Verdict::Correct);
+        assert_eq!(record.verdict("43"), Verdict::Wrong(Some(Hint::TooHigh)));
+        assert_eq!(record.verdict("EHZ"), Verdict::Wrong(None));
+    }
+
+    #[test]
+    fn wrong_guesses_bound_the_answer() {
+        let mut record = Record::default();
+        record.reject("100", Some(Hint::TooHigh));
+        record.reject("10", Some(Hint::TooLow));
+        record.reject("50", None);
+        assert_eq!(record.verdict("100"), Verdict::Wrong(Some(Hint::TooHigh)));
+        assert_eq!(record.verdict("120"), Verdict::Wrong(Some(Hint::TooHigh)));
+        assert_eq!(record.verdict("5"), Verdict::Wrong(Some(Hint::TooLow)));
+        assert_eq!(record.verdict("50"), Verdict::Wrong(None));
+        assert_eq!(record.verdict("60"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn accept_forgets_wrong_guess() {
+        let mut record = Record::default();
+        record.reject("60", Some(Hint::TooHigh));
+        record.accept("60");
+        assert_eq!(record.verdict("60"), Verdict::Correct);
+    }
+}
@@ -0,0 +1,234 @@
+//! Animations of the simulations behind some of the puzzles.
+//!
+//! A day replays a part through [`Solution::visualize`](crate::Solution),
+//! emitting a [`Frame`] to a [`Sink`] for every step it wants shown. The
+//! sink decides what to do with them, e.g. [`terminal::Terminal`] draws them
+//! live, and [`export::Recorder`] writes them to an animated image.
+
+use crate::grid::{Grid, Pos};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub mod export;
+pub mod terminal;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(110, 110, 110);
+
+    /// The colour `t` of the way from `self` to `other`, for `t` in `0..=1`.
+    pub fn lerp(self, other: Rgb, t: f32) -> Rgb {
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+}
+
+/// One character cell of a frame. Cells showing a space are background.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+impl Cell {
+    pub const BLANK: Cell = Cell::new(' ', Rgb::WHITE);
+
+    pub const fn new(glyph: char, color: Rgb) -> Self {
+        Self { glyph, color }
+    }
+}
+
+/// A single step of an animation, as a rectangle of cells.
+pub trait Frame {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    fn cell(&self, pos: Pos) -> Cell;
+
+    /// The cell to keep in view when the frame does not fit, if any.
+    fn focus(&self) -> Option<Pos> {
+        None
+    }
+}
+
+/// How a grid's cells are drawn, for grids used directly as frames.
+pub trait Tile {
+    fn cell(&self) -> Cell;
+}
+
+impl<T: Tile> Frame for Grid<T> {
+    fn width(&self) -> usize {
+        Grid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Grid::height(self)
+    }
+
+    fn cell(&self, pos: Pos) -> Cell {
+        self[pos].cell()
+    }
+}
+
+/// Somewhere for frames to go.
+pub trait Sink {
+    fn frame(&mut self, frame: &dyn Frame);
+}
+
+/// Colours to draw cells with instead of their own, by glyph, and the colour
+/// of the background behind blank cells in recordings.
+///
+/// Tiles are told apart by glyph, so e.g. `#=808080,o=deb860,bg=101018`
+/// recolours the rock `#` and sand `o` of day 14, and the background.
+#[derive(Clone, Debug, Default)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    background: Option<Rgb>,
+}
+
+impl Palette {
+    pub const BACKGROUND: Rgb = Rgb(16, 16, 24);
+
+    pub fn paint(&self, cell: Cell) -> Cell {
+        match self.colors.get(&cell.glyph) {
+            Some(&color) => Cell::new(cell.glyph, color),
+            None => cell,
+        }
+    }
+
+    pub fn background(&self) -> Rgb {
+        self.background.unwrap_or(Self::BACKGROUND)
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let value = u32::from_str_radix(hex, 16)
+            .ok()
+            .filter(|_| hex.len() == 6)
+            .ok_or_else(|| format!("expected a colour `rrggbb`, found `{}`", s))?;
+        let [_, r, g, b] = value.to_be_bytes();
+        Ok(Rgb(r, g, b))
+    }
+}
+
+impl FromStr for Palette {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut palette = Palette::default();
+        for entry in s.split(',') {
+            let (key, color) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("expected `glyph=rrggbb`, found `{}`", entry))?;
+            let color = color.parse()?;
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                _ if key == "bg" => palette.background = Some(color),
+                (Some(glyph), None) => {
+                    palette.colors.insert(glyph, color);
+                }
+                _ => return Err(format!("expected a glyph or `bg`, found `{}`", key)),
+            }
+        }
+        Ok(palette)
+    }
+}
+
+struct Painted<'a> {
+    frame: &'a dyn Frame,
+    palette: &'a Palette,
+}
+
+impl Frame for Painted<'_> {
+    fn width(&self) -> usize {
+        self.frame.width()
+    }
+
+    fn height(&self) -> usize {
+        self.frame.height()
+    }
+
+    fn cell(&self, pos: Pos) -> Cell {
+        self.palette.paint(self.frame.cell(pos))
+    }
+
+    fn focus(&self) -> Option<Pos> {
+        self.frame.focus()
+    }
+}
+
+/// Passes frames on to `sink`, recoloured by `palette`.
+pub struct Painter<'a> {
+    pub palette: &'a Palette,
+    pub sink: &'a mut dyn Sink,
+}
+
+impl Sink for Painter<'_> {
+    fn frame(&mut self, frame: &dyn Frame) {
+        self.sink.frame(&Painted {
+            frame,
+            palette: self.palette,
+        });
+    }
+}
+
+/// Options for animating a solution.
+#[derive(clap::Args, Clone, Debug)]
+pub struct VisualizeArgs {
+    /// Animate the solution in the terminal, for days that support it
+    #[arg(long)]
+    pub visualize: bool,
+
+    /// Write the animation to this `.gif` or `.png` (APNG) file instead
+    #[arg(long, value_name = "FILE", conflicts_with = "visualize")]
+    pub record: Option<PathBuf>,
+
+    /// The most frames per second to animate at
+    #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u32).range(1..))]
+    pub fps: u32,
+
+    /// Colours to draw glyphs with, as `glyph=rrggbb` and `bg=rrggbb` for the
+    /// background, e.g. `#=808080,o=deb860,bg=101018` for day 14's rock and sand
+    #[arg(long)]
+    pub palette: Option<Palette>,
+
+    /// Pixels per cell side in recordings
+    #[arg(long, default_value_t = 4, requires = "record", value_parser = clap::value_parser!(u32).range(1..=64))]
+    pub scale: u32,
+
+    /// Only record every Nth frame, though the last frame is always kept
+    #[arg(long, default_value_t = 1, requires = "record", value_parser = clap::value_parser!(u32).range(1..))]
+    pub every: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette() {
+        let palette: Palette = "#=808080,o=#deb860,bg=101018".parse().unwrap();
+        assert_eq!(palette.background(), Rgb(0x10, 0x10, 0x18));
+        let rock = palette.paint(Cell::new('#', Rgb::WHITE));
+        assert_eq!(rock, Cell::new('#', Rgb(0x80, 0x80, 0x80)));
+        let air = palette.paint(Cell::BLANK);
+        assert_eq!(air, Cell::BLANK);
+
+        assert!("#=80808".parse::<Palette>().is_err());
+        assert!("ab=808080".parse::<Palette>().is_err());
+        assert!("#808080".parse::<Palette>().is_err());
+    }
+}
@@ -0,0 +1,309 @@
+use super::{Frame, Rgb, Sink};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+/// Counts the frames of an animation and the largest of them, which is
+/// what a [`Recorder`] needs to know up front.
+#[derive(Clone, Debug, Default)]
+pub struct Survey {
+    pub frames: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Sink for Survey {
+    fn frame(&mut self, frame: &dyn Frame) {
+        self.frames += 1;
+        self.width = self.width.max(frame.width());
+        self.height = self.height.max(frame.height());
+    }
+}
+
+/// How frames are drawn and kept when recording.
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub fps: u32,
+    /// Pixels per cell side.
+    pub scale: u32,
+    /// Keep only every `every`th frame, and the last.
+    pub every: u32,
+    pub background: Rgb,
+}
+
+enum Encoder {
+    Gif(gif::Encoder<BufWriter<File>>),
+    Apng(png::Writer<BufWriter<File>>),
+}
+
+/// Writes frames to an animated GIF or APNG, by the extension of the path,
+/// drawing each cell as a square of its colour.
+///
+/// Every frame is drawn from the top left of a canvas as large as the
+/// largest frame, so the animation has to be surveyed first.
+pub struct Recorder {
+    encoder: Encoder,
+    options: Options,
+    width: usize,
+    height: usize,
+    frames: usize,
+    seen: usize,
+    written: usize,
+    error: Option<String>,
+}
+
+/// How many of `frames` frames are kept when keeping every `every`th one
+/// and the last.
+fn kept(frames: usize, every: usize) -> usize {
+    frames.div_ceil(every) + usize::from(frames > 0 && !(frames - 1).is_multiple_of(every))
+}
+
+impl Recorder {
+    pub fn create(path: &Path, survey: &Survey, options: Options) -> Result<Self, String> {
+        let width = survey.width * options.scale as usize;
+        let height = survey.height * options.scale as usize;
+        if width > u16::MAX as usize || height > u16::MAX as usize {
+            return Err(format!("a {}x{} recording is too large", width, height));
+        }
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        let cannot_write =
+            |err: &dyn std::fmt::Display| format!("cannot write {}: {}", path.display(), err);
+        let file = || {
+            File::create(path)
+                .map(BufWriter::new)
+                .map_err(|err| cannot_write(&err))
+        };
+
+        let encoder = match extension {
+            Some("gif") => {
+                let mut encoder = gif::Encoder::new(file()?, width as u16, height as u16, &[])
+                    .map_err(|err| cannot_write(&err))?;
+                encoder
+                    .set_repeat(gif::Repeat::Infinite)
+                    .map_err(|err| cannot_write(&err))?;
+                Encoder::Gif(encoder)
+            }
+            Some("png" | "apng") => {
+                let mut encoder = png::Encoder::new(file()?, width as u32, height as u32);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                let frames = kept(survey.frames, options.every as usize);
+                encoder
+                    .set_animated(frames as u32, 0)
+                    .and_then(|()| {
+                        encoder.set_frame_delay(1, options.fps.min(u16::MAX as u32) as u16)
+                    })
+                    .map_err(|err| cannot_write(&err))?;
+                Encoder::Apng(encoder.write_header().map_err(|err| cannot_write(&err))?)
+            }
+            _ => {
+                return Err(format!(
+                    "cannot record to {}, expected a `.gif` or `.png` file",
+                    path.display()
+                ))
+            }
+        };
+
+        Ok(Self {
+            encoder,
+            options,
+            width: survey.width,
+            height: survey.height,
+            frames: survey.frames,
+            seen: 0,
+            written: 0,
+            error: None,
+        })
+    }
+
+    /// The frame as one colour per pixel, row by row.
+    fn pixels(&self, frame: &dyn Frame) -> Vec<Rgb> {
+        let scale = self.options.scale as usize;
+        let mut pixels = Vec::with_capacity(self.width * self.height * scale * scale);
+        for row in 0..self.height {
+            let start = pixels.len();
+            for col in 0..self.width {
+                let color = if row < frame.height() && col < frame.width() {
+                    let cell = frame.cell((row, col));
+                    if cell.glyph == ' ' {
+                        self.options.background
+                    } else {
+                        cell.color
+                    }
+                } else {
+                    self.options.background
+                };
+                pixels.extend(std::iter::repeat_n(color, scale));
+            }
+            for _ in 1..scale {
+                pixels.extend_from_within(start..start + self.width * scale);
+            }
+        }
+        pixels
+    }
+
+    fn write(&mut self, frame: &dyn Frame) -> Result<(), String> {
+        let pixels = self.pixels(frame);
+        match &mut self.encoder {
+            Encoder::Gif(encoder) => {
+                let (width, height) = (
+                    (self.width * self.options.scale as usize) as u16,
+                    (self.height * self.options.scale as usize) as u16,
+                );
+                let mut indices: HashMap<Rgb, u8> = HashMap::new();
+                let mut buffer = Vec::with_capacity(pixels.len());
+                for &color in &pixels {
+                    let next = indices.len();
+                    if next == 256 && !indices.contains_key(&color) {
+                        break;
+                    }
+                    buffer.push(*indices.entry(color).or_insert(next as u8));
+                }
+                let mut frame = if buffer.len() == pixels.len() {
+                    let mut palette = vec![0; indices.len() * 3];
+                    for (color, &i) in &indices {
+                        palette[i as usize * 3..][..3]
+                            .copy_from_slice(&[color.0, color.1, color.2]);
+                    }
+                    gif::Frame {
+                        width,
+                        height,
+                        buffer: Cow::Owned(buffer),
+                        palette: Some(palette),
+                        ..gif::Frame::default()
+                    }
+                } else {
+                    // Too many colours for one palette, so let the encoder pick.
+                    let rgb: Vec<u8> = pixels.iter().flat_map(|c| [c.0, c.1, c.2]).collect();
+                    gif::Frame::from_rgb_speed(width, height, &rgb, 10)
+                };
+                frame.delay = (100 / self.options.fps).max(2) as u16;
+                encoder.write_frame(&frame).map_err(|err| err.to_string())
+            }
+            Encoder::Apng(writer) => {
+                let rgb: Vec<u8> = pixels.iter().flat_map(|c| [c.0, c.1, c.2]).collect();
+                writer.write_image_data(&rgb).map_err(|err| err.to_string())
+            }
+        }
+    }
+
+    /// Finishes the file, reporting the first error hit while recording,
+    /// and returns how many frames were written.
+    pub fn finish(self) -> Result<usize, String> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        match self.encoder {
+            Encoder::Gif(encoder) => encoder
+                .into_inner()
+                .map(drop)
+                .map_err(|err| err.to_string())?,
+            Encoder::Apng(writer) => writer.finish().map_err(|err| err.to_string())?,
+        }
+        Ok(self.written)
+    }
+}
+
+impl Sink for Recorder {
+    fn frame(&mut self, frame: &dyn Frame) {
+        let i = self.seen;
+        self.seen += 1;
+        let every = self.options.every as usize;
+        if self.error.is_some() || (!i.is_multiple_of(every) && i + 1 != self.frames) {
+            return;
+        }
+        match self.write(frame) {
+            Ok(()) => self.written += 1,
+            Err(err) => self.error = Some(format!("cannot record frame {}: {}", i, err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::visualize::{Cell, Tile};
+
+    impl Tile for bool {
+        fn cell(&self) -> Cell {
+            if *self {
+                Cell::new('#', Rgb::WHITE)
+            } else {
+                Cell::BLANK
+            }
+        }
+    }
+
+    /// Records three frames of a 3x2 grid filling up, every other frame.
+    fn record(path: &Path) -> usize {
+        let mut grid = Grid::from_elem(2, 3, false);
+        let survey = Survey {
+            frames: 3,
+            width: 3,
+            height: 2,
+        };
+        let options = Options {
+            fps: 10,
+            scale: 2,
+            every: 2,
+            background: Rgb(0, 0, 0),
+        };
+        let mut recorder = Recorder::create(path, &survey, options).unwrap();
+        for pos in [(0, 0), (0, 1), (1, 2)] {
+            grid[pos] = true;
+            recorder.frame(&grid);
+        }
+        recorder.finish().unwrap()
+    }
+
+    #[test]
+    fn kept_frames() {
+        assert_eq!(kept(0, 3), 0);
+        assert_eq!(kept(1, 3), 1);
+        assert_eq!(kept(7, 3), 3);
+        assert_eq!(kept(8, 3), 4);
+        assert_eq!(kept(8, 1), 8);
+    }
+
+    #[test]
+    fn gif_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-export-{}.gif", std::process::id()));
+        assert_eq!(record(&path), 2);
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(File::open(&path).unwrap()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (6, 4));
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push(frame.buffer.to_vec());
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(frames.len(), 2);
+        let pixel = |frame: &[u8], x: usize, y: usize| frame[(y * 6 + x) * 4];
+        assert_eq!(pixel(&frames[0], 0, 0), 255);
+        assert_eq!(pixel(&frames[0], 2, 0), 0);
+        assert_eq!(pixel(&frames[1], 5, 3), 255);
+    }
+
+    #[test]
+    fn apng_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-export-{}.png", std::process::id()));
+        assert_eq!(record(&path), 2);
+
+        let decoder = png::Decoder::new(File::open(&path).unwrap());
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        let frames = info.animation_control().map(|control| control.num_frames);
+        let size = (info.width, info.height);
+        drop(reader);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(size, (6, 4));
+        assert_eq!(frames, Some(2));
+    }
+}
@@ -0,0 +1,244 @@
+use super::{Frame, Sink};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{cursor, execute, queue};
+use std::io::{self, Stdout, Write};
+use std::time::{Duration, Instant};
+
+const KEYS: &str = "space: pause  n: step  +/-: speed  q: quit";
+
+/// Plays frames in the terminal's alternate screen, at most `fps` frames per
+/// second. Frames that do not fit are cropped around their focus.
+///
+/// The terminal is only taken over once the first frame arrives, and is
+/// restored by [`Terminal::close`], or on drop.
+pub struct Terminal {
+    out: Stdout,
+    title: String,
+    interval: Duration,
+    shown: Option<Instant>,
+    frames: usize,
+    total: usize,
+    active: bool,
+    paused: bool,
+    done: bool,
+    quit: bool,
+    error: Option<io::Error>,
+}
+
+impl Terminal {
+    pub fn new(fps: u32) -> Self {
+        Self {
+            out: io::stdout(),
+            title: String::new(),
+            interval: Duration::from_secs(1) / fps.max(1),
+            shown: None,
+            frames: 0,
+            total: 0,
+            active: false,
+            paused: false,
+            done: false,
+            quit: false,
+            error: None,
+        }
+    }
+
+    /// Starts a new animation, shown with `title` in the status line.
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+        self.frames = 0;
+        self.shown = None;
+    }
+
+    /// Whether the user asked to stop watching.
+    pub fn quit(&self) -> bool {
+        self.quit
+    }
+
+    /// How many frames have been shown in total.
+    pub fn shown(&self) -> usize {
+        self.total
+    }
+
+    /// Keeps the last frame of the current animation on screen until a key
+    /// is pressed.
+    pub fn hold(&mut self) {
+        if self.active && self.frames > 0 && !self.quit {
+            self.done = true;
+            let result = self.wait_for_key();
+            self.done = false;
+            self.fail(result);
+        }
+    }
+
+    /// Gives the terminal back, reporting the first error hit while drawing.
+    pub fn close(mut self) -> io::Result<()> {
+        let result = self.restore();
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => result,
+        }
+    }
+
+    fn fail(&mut self, result: io::Result<()>) {
+        if let Err(err) = result {
+            self.error.get_or_insert(err);
+            self.quit = true;
+        }
+    }
+
+    fn restore(&mut self) -> io::Result<()> {
+        if !self.active {
+            return Ok(());
+        }
+        self.active = false;
+        execute!(self.out, ResetColor, cursor::Show, LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()
+    }
+
+    /// Handles a key press, returning whether it asks for the next frame.
+    fn key(&mut self, key: KeyEvent) -> bool {
+        if key.kind != KeyEventKind::Press {
+            return false;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self.quit = true,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('n') | KeyCode::Right => return self.paused,
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.interval = (self.interval / 2).max(Duration::from_micros(100))
+            }
+            KeyCode::Char('-') => self.interval = (self.interval * 2).min(Duration::from_secs(2)),
+            _ => {}
+        }
+        false
+    }
+
+    /// Waits until the next frame is due, handling keys meanwhile.
+    fn wait(&mut self) -> io::Result<()> {
+        let Some(shown) = self.shown else {
+            return Ok(());
+        };
+        while !self.quit {
+            let timeout = if self.paused {
+                Duration::from_millis(250)
+            } else {
+                let due = shown + self.interval;
+                match due.checked_duration_since(Instant::now()) {
+                    Some(timeout) if !timeout.is_zero() => timeout,
+                    _ => break,
+                }
+            };
+            if event::poll(timeout)? {
+                if let Event::Key(key) = event::read()? {
+                    if self.key(key) {
+                        break;
+                    }
+                    self.status()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn wait_for_key(&mut self) -> io::Result<()> {
+        self.status()?;
+        loop {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    self.key(key);
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn status(&mut self) -> io::Result<()> {
+        let (cols, rows) = terminal::size()?;
+        let state = if self.done {
+            "done, press any key".to_string()
+        } else {
+            let fps = 1.0 / self.interval.as_secs_f64();
+            let paused = if self.paused { " [paused]" } else { "" };
+            format!("{:.0} fps{}  {}", fps, paused, KEYS)
+        };
+        let status = format!("{}  frame {}  {}", self.title, self.frames, state);
+        let status: String = status.chars().take(cols as usize).collect();
+        queue!(
+            self.out,
+            cursor::MoveTo(0, rows.saturating_sub(1)),
+            ResetColor,
+            Print(status),
+            Clear(ClearType::UntilNewLine)
+        )?;
+        self.out.flush()
+    }
+
+    fn draw(&mut self, frame: &dyn Frame) -> io::Result<()> {
+        if !self.active {
+            terminal::enable_raw_mode()?;
+            execute!(self.out, EnterAlternateScreen, cursor::Hide)?;
+            self.active = true;
+        }
+
+        let (cols, rows) = terminal::size()?;
+        let width = frame.width().min(cols as usize);
+        let height = frame.height().min(rows.saturating_sub(1) as usize);
+        let offset = |len: usize, view: usize, focus: Option<usize>| {
+            focus.map_or(0, |focus| focus.saturating_sub(view / 2).min(len - view))
+        };
+        let focus = frame.focus();
+        let top = offset(frame.height(), height, focus.map(|pos| pos.0));
+        let left = offset(frame.width(), width, focus.map(|pos| pos.1));
+
+        for row in 0..height {
+            queue!(self.out, cursor::MoveTo(0, row as u16))?;
+            let mut color = None;
+            let mut run = String::new();
+            for col in 0..width {
+                let cell = frame.cell((top + row, left + col));
+                if cell.glyph != ' ' && color != Some(cell.color) {
+                    queue!(self.out, Print(&run))?;
+                    run.clear();
+                    let (r, g, b) = (cell.color.0, cell.color.1, cell.color.2);
+                    queue!(self.out, SetForegroundColor(Color::Rgb { r, g, b }))?;
+                    color = Some(cell.color);
+                }
+                run.push(cell.glyph);
+            }
+            queue!(self.out, Print(run), Clear(ClearType::UntilNewLine))?;
+        }
+        queue!(
+            self.out,
+            cursor::MoveTo(0, height as u16),
+            Clear(ClearType::FromCursorDown)
+        )?;
+        self.status()
+    }
+}
+
+impl Sink for Terminal {
+    fn frame(&mut self, frame: &dyn Frame) {
+        if self.quit {
+            return;
+        }
+        let result = self.wait().and_then(|()| {
+            if self.quit {
+                return Ok(());
+            }
+            self.frames += 1;
+            self.total += 1;
+            self.draw(frame)
+        });
+        self.shown = Some(Instant::now());
+        self.fail(result);
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = self.restore();
+    }
+}
@@ -0,0 +1,131 @@
+use std::ops::{Index, IndexMut};
+
+/// A position in a voxel grid, as `(x, y, z)`.
+pub type Voxel = (usize, usize, usize);
+
+/// The six face-to-face steps, as `(x, y, z)` steps.
+pub const DIRECTIONS6: [(isize, isize, isize); 6] = [
+    (-1, 0, 0),
+    (1, 0, 0),
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+];
+
+/// A dense 3D grid, the counterpart of [`Grid`](crate::grid::Grid).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VoxelGrid<T> {
+    size: Voxel,
+    cells: Vec<T>,
+}
+
+impl<T> VoxelGrid<T> {
+    /// The number of voxels along each axis.
+    pub fn size(&self) -> Voxel {
+        self.size
+    }
+
+    fn offset(&self, voxel: Voxel) -> Option<usize> {
+        let (x, y, z) = voxel;
+        let (sx, sy, sz) = self.size;
+        (x < sx && y < sy && z < sz).then(|| (z * sy + y) * sx + x)
+    }
+
+    pub fn get(&self, voxel: Voxel) -> Option<&T> {
+        self.offset(voxel).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, voxel: Voxel) -> Option<&mut T> {
+        self.offset(voxel).map(|i| &mut self.cells[i])
+    }
+
+    /// `voxel` moved one `step`, if that is still inside the grid.
+    pub fn step(&self, voxel: Voxel, step: (isize, isize, isize)) -> Option<Voxel> {
+        let next = (
+            voxel.0.checked_add_signed(step.0)?,
+            voxel.1.checked_add_signed(step.1)?,
+            voxel.2.checked_add_signed(step.2)?,
+        );
+        self.offset(next).map(|_| next)
+    }
+
+    /// The up to six face-to-face neighbours of `voxel` inside the grid.
+    pub fn neighbours6(&self, voxel: Voxel) -> impl Iterator<Item = Voxel> + '_ {
+        DIRECTIONS6
+            .into_iter()
+            .filter_map(move |step| self.step(voxel, step))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every voxel along with its position, x fastest.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Voxel, &T)> {
+        let (sx, sy, _) = self.size;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % sx, i / sx % sy, i / (sx * sy)), cell))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> VoxelGrid<U> {
+        VoxelGrid {
+            size: self.size,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> VoxelGrid<T> {
+    pub fn from_elem(size: Voxel, value: T) -> Self {
+        Self {
+            size,
+            cells: vec![value; size.0 * size.1 * size.2],
+        }
+    }
+}
+
+impl<T> Index<Voxel> for VoxelGrid<T> {
+    type Output = T;
+
+    fn index(&self, voxel: Voxel) -> &T {
+        let i = self
+            .offset(voxel)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", voxel));
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<Voxel> for VoxelGrid<T> {
+    fn index_mut(&mut self, voxel: Voxel) -> &mut T {
+        let i = self
+            .offset(voxel)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", voxel));
+        &mut self.cells[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_round_trip() {
+        let mut grid = VoxelGrid::from_elem((2, 3, 4), 0);
+        grid[(1, 2, 3)] = 7;
+        assert_eq!(grid.get((1, 2, 3)), Some(&7));
+        assert_eq!(grid.get((2, 0, 0)), None);
+        let found: Vec<_> = grid.indexed_iter().filter(|(_, &v)| v == 7).collect();
+        assert_eq!(found, [((1, 2, 3), &7)]);
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = VoxelGrid::from_elem((3, 3, 3), ());
+        assert_eq!(grid.neighbours6((0, 0, 0)).count(), 3);
+        assert_eq!(grid.neighbours6((1, 1, 1)).count(), 6);
+        assert_eq!(grid.neighbours6((2, 1, 0)).count(), 4);
+    }
+}
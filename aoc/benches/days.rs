@@ -0,0 +1,73 @@
+//! Times parsing, part 1 and part 2 of every day on its real input.
+//!
+//! Days without an input are skipped. Criterion's own options apply, e.g.
+//!
+//! ```text
+//! cargo bench -p aoc --bench days -- day16            # only day 16
+//! cargo bench -p aoc --bench days -- --save-baseline main
+//! cargo bench -p aoc --bench days -- --baseline main  # compare against `main`
+//! ```
+//!
+//! The HTML report ends up in `target/criterion/report/index.html`.
+
+use aoc_common::{input, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let s = match input::read(S::DAY, None) {
+        Ok(s) => s,
+        Err(err) => {
+            eprintln!("day {}: {}, skipping", S::DAY, err);
+            return;
+        }
+    };
+    let input = match S::parse(&s) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("day {}: {}, skipping", S::DAY, err.locate(&s));
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&s))));
+    group.bench_function("part1", |b| {
+        b.iter(|| S::part1(black_box(&input)).to_string())
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| S::part2(black_box(&input)).to_string())
+    });
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<aoc01::Day01>(c);
+    bench_day::<aoc02::Day02>(c);
+    bench_day::<aoc03::Day03>(c);
+    bench_day::<aoc04::Day04>(c);
+    bench_day::<aoc05::Day05>(c);
+    bench_day::<aoc06::Day06>(c);
+    bench_day::<aoc07::Day07>(c);
+    bench_day::<aoc08::Day08>(c);
+    bench_day::<aoc09::Day09>(c);
+    bench_day::<aoc10::Day10>(c);
+    bench_day::<aoc11::Day11>(c);
+    bench_day::<aoc12::Day12>(c);
+    bench_day::<aoc13::Day13>(c);
+    bench_day::<aoc14::Day14>(c);
+    bench_day::<aoc15::Day15>(c);
+    bench_day::<aoc16::Day16>(c);
+    bench_day::<aoc17::Day17>(c);
+    bench_day::<aoc18::Day18>(c);
+    bench_day::<aoc19::Day19>(c);
+    bench_day::<aoc20::Day20>(c);
+    bench_day::<aoc21::Day21>(c);
+    bench_day::<aoc22::Day22>(c);
+    bench_day::<aoc23::Day23>(c);
+    bench_day::<aoc24::Day24>(c);
+    bench_day::<aoc25::Day25>(c);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
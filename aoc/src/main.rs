@@ -0,0 +1,215 @@
+use aoc_common::{input, Args, ParseError, Report};
+use clap::{Parser, Subcommand};
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::time::Duration;
+
+type Solver = fn(&str, Option<u8>) -> Result<Report, ParseError>;
+
+const DAYS: &[(u8, Solver)] = &[
+    (1, aoc_common::solve::<aoc01::Day01>),
+    (2, aoc_common::solve::<aoc02::Day02>),
+    (3, aoc_common::solve::<aoc03::Day03>),
+    (4, aoc_common::solve::<aoc04::Day04>),
+    (5, aoc_common::solve::<aoc05::Day05>),
+    (6, aoc_common::solve::<aoc06::Day06>),
+    (7, aoc_common::solve::<aoc07::Day07>),
+    (8, aoc_common::solve::<aoc08::Day08>),
+    (9, aoc_common::solve::<aoc09::Day09>),
+    (10, aoc_common::solve::<aoc10::Day10>),
+    (11, aoc_common::solve::<aoc11::Day11>),
+    (12, aoc_common::solve::<aoc12::Day12>),
+    (13, aoc_common::solve::<aoc13::Day13>),
+    (14, aoc_common::solve::<aoc14::Day14>),
+    (15, aoc_common::solve::<aoc15::Day15>),
+    (16, aoc_common::solve::<aoc16::Day16>),
+    (17, aoc_common::solve::<aoc17::Day17>),
+    (18, aoc_common::solve::<aoc18::Day18>),
+    (19, aoc_common::solve::<aoc19::Day19>),
+    (20, aoc_common::solve::<aoc20::Day20>),
+    (21, aoc_common::solve::<aoc21::Day21>),
+    (22, aoc_common::solve::<aoc22::Day22>),
+    (23, aoc_common::solve::<aoc23::Day23>),
+    (24, aoc_common::solve::<aoc24::Day24>),
+    (25, aoc_common::solve::<aoc25::Day25>),
+];
+
+/// Which days to run: a single day, an inclusive range, or all of them.
+#[derive(Clone, Debug)]
+struct Days(RangeInclusive<u8>);
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("`{}` is not a day between 1 and 25", s)),
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let days = if s == "all" {
+            1..=25
+        } else if let Some((start, end)) = s.split_once('-') {
+            parse_day(start)?..=parse_day(end)?
+        } else {
+            let day = parse_day(s)?;
+            day..=day
+        };
+        if days.is_empty() {
+            return Err(format!("`{}` is an empty range of days", s));
+        }
+        Ok(Days(days))
+    }
+}
+
+impl Display for Days {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.start() == self.0.end() {
+            write!(f, "{}", self.0.start())
+        } else {
+            write!(f, "{}-{}", self.0.start(), self.0.end())
+        }
+    }
+}
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days and print a table of answers and timings
+    Run {
+        /// A day (`7`), a range of days (`10-16`) or `all`
+        days: Days,
+
+        #[command(flatten)]
+        args: Args,
+    },
+}
+
+struct Row {
+    day: u8,
+    part: u8,
+    answer: String,
+    parse: Option<Duration>,
+    elapsed: Duration,
+}
+
+fn print_table(rows: &[Row]) {
+    let answers: Vec<&str> = rows
+        .iter()
+        .map(|row| {
+            if row.answer.contains('\n') {
+                "(see below)"
+            } else {
+                row.answer.as_str()
+            }
+        })
+        .collect();
+    let width = answers
+        .iter()
+        .map(|answer| answer.len())
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .unwrap();
+
+    println!(
+        "Day  Part  {:<width$}  {:>10}  {:>10}",
+        "Answer", "Parse", "Time"
+    );
+    let mut prev_day = None;
+    for (row, answer) in rows.iter().zip(answers) {
+        let day = if prev_day == Some(row.day) {
+            String::new()
+        } else {
+            row.day.to_string()
+        };
+        let parse = row
+            .parse
+            .map(|parse| format!("{:.2?}", parse))
+            .unwrap_or_default();
+        let elapsed = format!("{:.2?}", row.elapsed);
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {:>10}  {:>10}",
+            day, row.part, answer, parse, elapsed
+        );
+        prev_day = Some(row.day);
+    }
+
+    let total: Duration = rows
+        .iter()
+        .map(|row| row.parse.unwrap_or_default() + row.elapsed)
+        .sum();
+    let total = format!("{:.2?}", total);
+    println!("{:<5}{:>width$}", "Total", total, width = width + 30);
+
+    for row in rows.iter().filter(|row| row.answer.contains('\n')) {
+        println!("\nDay {} part {}:\n{}", row.day, row.part, row.answer);
+    }
+}
+
+fn run(days: Days, args: Args) -> Result<(), String> {
+    let solvers: Vec<_> = DAYS
+        .iter()
+        .filter(|(day, _)| days.0.contains(day))
+        .collect();
+    if solvers.is_empty() {
+        return Err(format!("no solutions for day {}", days));
+    }
+    if args.input.is_some() && solvers.len() > 1 {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    let mut rows = Vec::new();
+    for &(day, solve) in solvers {
+        let input = match input::read(day, args.input.as_deref()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("day {}: {}", day, err);
+                continue;
+            }
+        };
+
+        let report = match solve(&input, args.part) {
+            Ok(report) => report,
+            Err(err) => {
+                eprintln!("day {}: error: {}", day, err.diagnostic());
+                continue;
+            }
+        };
+        let mut parse = Some(report.parse);
+        for answer in report.answers {
+            rows.push(Row {
+                day,
+                part: answer.part,
+                answer: answer.answer,
+                parse: parse.take(),
+                elapsed: answer.elapsed,
+            });
+        }
+    }
+
+    if rows.is_empty() {
+        return Err(format!("could not solve day {}", days));
+    }
+    print_table(&rows);
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run { days, args } => run(days, args),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,209 @@
+use aoc_common::top_k::TopK;
+use aoc_common::{parse, ParseError, Solution};
+use std::fmt::Display;
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub mod stats;
+
+/// How many calories one elf carries, and where that elf came in the input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Carrier {
+    /// Counting from 1, as the puzzle does.
+    pub elf: usize,
+    pub calories: u32,
+}
+
+/// The calories each elf carries, read from `reader` a line at a time.
+/// Errors end the stream.
+pub fn totals(reader: impl BufRead) -> impl Iterator<Item = Result<u32, String>> {
+    let mut lines = reader.lines().enumerate();
+    let mut done = false;
+    std::iter::from_fn(move || {
+        let mut total = None;
+        while !done {
+            match lines.next() {
+                None => done = true,
+                Some((_, Err(err))) => {
+                    done = true;
+                    return Some(Err(format!("cannot read input: {}", err)));
+                }
+                Some((_, Ok(line))) if line.is_empty() => {
+                    if total.is_some() {
+                        break;
+                    }
+                }
+                Some((i, Ok(line))) => match parse::number::<u32>(&line) {
+                    Ok(calories) => *total.get_or_insert(0) += calories,
+                    Err(err) => {
+                        done = true;
+                        return Some(Err(err.locate_line(&line, i + 1).diagnostic()));
+                    }
+                },
+            }
+        }
+        total.map(Ok)
+    })
+}
+
+/// The `k` elves carrying the most calories, most first, from a stream of
+/// every elf's total. Only those `k` are held on to.
+pub fn top_k(totals: impl IntoIterator<Item = u32>, k: usize) -> Vec<Carrier> {
+    totals
+        .into_iter()
+        .zip(1..)
+        .map(|(calories, elf)| Carrier { elf, calories })
+        .top_k_by_key(k, |carrier| carrier.calories)
+        .collect()
+}
+
+/// The `k` elves carrying the most calories in the input read from
+/// `reader`, a line at a time and without holding the whole input. Stops
+/// at the first error.
+pub fn top_carriers(reader: impl BufRead, k: usize) -> Result<Vec<Carrier>, String> {
+    let mut error = None;
+    let totals = totals(reader).map_while(|total| total.map_err(|err| error = Some(err)).ok());
+    let top = top_k(totals, k);
+    match error {
+        Some(err) => Err(err),
+        None => Ok(top),
+    }
+}
+
+type Elf = Vec<u32>;
+
+#[derive(Clone, Debug)]
+pub struct Input {
+    elves: Vec<Elf>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let elves = s
+            .split("\n\n")
+            .map(|elf| elf.lines().map(parse::number).collect())
+            .collect::<Result<_, _>>()?;
+        Ok(Self { elves })
+    }
+}
+
+impl Input {
+    /// The calories each elf carries.
+    fn totals(&self) -> impl Iterator<Item = u32> + '_ {
+        self.elves.iter().map(|elf| elf.iter().sum())
+    }
+
+    /// The `k` elves carrying the most calories, most first.
+    pub fn top_k(&self, k: usize) -> Vec<Carrier> {
+        top_k(self.totals(), k)
+    }
+}
+
+fn part1(input: &Input) -> impl Display {
+    input.top_k(1)[0].calories
+}
+
+fn part2(input: &Input) -> impl Display {
+    input
+        .top_k(3)
+        .iter()
+        .map(|carrier| carrier.calories)
+        .sum::<u32>()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn example_part1() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "24000");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "45000");
+    }
+
+    #[test]
+    fn top_carriers() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        let top = input.top_k(2);
+        assert_eq!(
+            top,
+            [
+                Carrier {
+                    elf: 4,
+                    calories: 24000
+                },
+                Carrier {
+                    elf: 3,
+                    calories: 11000
+                },
+            ]
+        );
+        assert_eq!(super::top_carriers(EXAMPLE.as_bytes(), 2), Ok(top));
+        // Blank lines at the end are not another elf.
+        let s = format!("{}\n\n", EXAMPLE);
+        assert_eq!(totals(s.as_bytes()).count(), 5);
+        let err = super::top_carriers("1000\n\n2000\nx\n\n3000".as_bytes(), 2).unwrap_err();
+        assert!(err.starts_with("line 4, column 1: expected a number, found `x`"));
+    }
+
+    #[test]
+    fn example_stats() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        let stats = stats::Stats::new(&input);
+        assert_eq!(stats.elves, 5);
+        assert_eq!((stats.items.sum, stats.items.max), (10, 3));
+        assert_eq!(stats.calories.sum, 55000);
+        assert_eq!(stats.calories.median, 10000.0);
+        let histogram: Vec<usize> = stats.histogram.iter().map(|bucket| bucket.elves).collect();
+        assert_eq!(histogram, [1, 1, 0, 2, 0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day01>();
+    }
+}
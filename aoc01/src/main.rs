@@ -1,44 +1,52 @@
-use std::fmt::Display;
+use aoc01::stats::Stats;
+use aoc01::Day01;
+use std::fmt::Write;
 
-type Elf = Vec<u32>;
-
-#[derive(Clone, Debug)]
-struct Input {
-    elves: Vec<Elf>,
+#[derive(Copy, Clone, clap::ValueEnum)]
+enum Format {
+    Table,
+    Json,
 }
 
-impl From<&'static str> for Input {
-    fn from(s: &'static str) -> Self {
-        let elves = s
-            .split("\n\n")
-            .map(|elf| elf.lines().map(|n| n.parse().unwrap()).collect())
-            .collect();
-        Self { elves }
-    }
-}
+#[derive(clap::Args)]
+struct Options {
+    /// List the K elves carrying the most calories instead of the answers
+    #[arg(long, value_name = "K")]
+    top: Option<usize>,
 
-fn part1(input: &Input) -> impl Display {
-    input
-        .elves
-        .iter()
-        .map(|elf| elf.iter().sum::<u32>())
-        .max()
-        .unwrap()
-}
-
-fn part2(input: &Input) -> impl Display {
-    let mut calories: Vec<u32> = input
-        .elves
-        .iter()
-        .map(|elf| elf.iter().sum::<u32>())
-        .collect();
-    calories.sort_by(|a, b| b.cmp(a));
-    calories.into_iter().take(3).sum::<u32>()
+    /// Report statistics over the elves instead of the answers
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "table",
+        conflicts_with = "top"
+    )]
+    stats: Option<Format>,
 }
 
 fn main() {
-    let bytes = include_bytes!("input.txt");
-    let input: Input = std::str::from_utf8(bytes).unwrap().into();
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
+    aoc_common::main_with::<Day01, Options>(|options, _, source| {
+        if let Some(format) = options.stats {
+            let stats = || {
+                let input = aoc_common::parse_input::<Day01>(&source.read()?)?;
+                let stats = Stats::new(&input);
+                Ok(match format {
+                    Format::Table => stats.to_string(),
+                    Format::Json => stats.to_json() + "\n",
+                })
+            };
+            return Some(stats());
+        }
+
+        let k = options.top?;
+        let top = || {
+            let mut report = String::new();
+            for carrier in aoc01::top_carriers(source.open()?, k)? {
+                writeln!(report, "elf {}: {}", carrier.elf, carrier.calories).unwrap();
+            }
+            Ok(report)
+        };
+        Some(top())
+    });
 }
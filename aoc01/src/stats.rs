@@ -0,0 +1,169 @@
+//! Summary statistics over the elves: how many items each carries, and how
+//! many calories.
+
+use crate::Input;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+/// The percentiles reported besides the median.
+const PERCENTILES: [u8; 4] = [10, 25, 75, 90];
+
+/// How many buckets the calorie histogram has.
+const BUCKETS: usize = 10;
+
+/// The widest bar in the histogram.
+const BAR: usize = 40;
+
+/// The distribution of one number over the elves.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Summary {
+    pub sum: u64,
+    pub mean: f64,
+    pub min: u32,
+    pub max: u32,
+    pub median: f64,
+    /// By percent, interpolated between the nearest elves.
+    pub percentiles: BTreeMap<u8, f64>,
+}
+
+/// The `p`th percentile of `sorted`, interpolating linearly between the
+/// closest ranks.
+fn percentile(sorted: &[u32], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+    let t = rank - below as f64;
+    sorted[below] as f64 * (1.0 - t) + sorted[above] as f64 * t
+}
+
+impl Summary {
+    /// Summarises `values`, of which there must be at least one.
+    fn new(mut values: Vec<u32>) -> Self {
+        values.sort_unstable();
+        let sum: u64 = values.iter().map(|&value| value as u64).sum();
+        Self {
+            sum,
+            mean: sum as f64 / values.len() as f64,
+            min: values[0],
+            max: values[values.len() - 1],
+            median: percentile(&values, 50.0),
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&p| (p, percentile(&values, p as f64)))
+                .collect(),
+        }
+    }
+}
+
+/// How many elves carry from `start` up to `end` calories, the last bucket
+/// including its `end` and the others not.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub start: u32,
+    pub end: u32,
+    pub elves: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Stats {
+    pub elves: usize,
+    /// Items per elf.
+    pub items: Summary,
+    /// Total calories per elf.
+    pub calories: Summary,
+    pub histogram: Vec<Bucket>,
+}
+
+impl Stats {
+    pub fn new(input: &Input) -> Self {
+        let totals: Vec<u32> = input.totals().collect();
+        let items = input.elves.iter().map(|elf| elf.len() as u32).collect();
+
+        let calories = Summary::new(totals.clone());
+        let (min, max) = (calories.min, calories.max);
+        let width = (max - min).div_ceil(BUCKETS as u32).max(1);
+        let mut histogram: Vec<Bucket> = (0..BUCKETS as u32)
+            .map(|i| Bucket {
+                start: min + i * width,
+                end: min + (i + 1) * width,
+                elves: 0,
+            })
+            // Rounding the width up can leave the last buckets empty.
+            .take_while(|bucket| bucket.start <= max)
+            .collect();
+        let last = histogram.len() - 1;
+        for total in totals {
+            histogram[(((total - min) / width) as usize).min(last)].elves += 1;
+        }
+
+        Self {
+            elves: input.elves.len(),
+            items: Summary::new(items),
+            calories,
+            histogram,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (items, calories) = (&self.items, &self.calories);
+        writeln!(f, "{:<8}  {:>10}  {:>12}", "", "Items", "Calories")?;
+        writeln!(f, "{:<8}  {:>10}  {:>12}", "Elves", self.elves, self.elves)?;
+        writeln!(f, "{:<8}  {:>10}  {:>12}", "Sum", items.sum, calories.sum)?;
+        writeln!(f, "{:<8}  {:>10}  {:>12}", "Min", items.min, calories.min)?;
+        writeln!(f, "{:<8}  {:>10}  {:>12}", "Max", items.max, calories.max)?;
+        writeln!(
+            f,
+            "{:<8}  {:>10.1}  {:>12.1}",
+            "Mean", items.mean, calories.mean
+        )?;
+        writeln!(
+            f,
+            "{:<8}  {:>10.1}  {:>12.1}",
+            "Median", items.median, calories.median
+        )?;
+        for (p, value) in &items.percentiles {
+            let name = format!("P{}", p);
+            writeln!(
+                f,
+                "{:<8}  {:>10.1}  {:>12.1}",
+                name, value, calories.percentiles[p]
+            )?;
+        }
+
+        writeln!(f, "\nCalories per elf")?;
+        let most = self.histogram.iter().map(|bucket| bucket.elves).max();
+        let digits = self.calories.max.to_string().len();
+        for bucket in &self.histogram {
+            let len = (bucket.elves * BAR).div_ceil(most.unwrap_or(1).max(1));
+            writeln!(
+                f,
+                "{:>digits$}..{:<digits$}  {:<BAR$}  {}",
+                bucket.start,
+                bucket.end,
+                "#".repeat(len),
+                bucket.elves,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles_interpolate() {
+        let values = [1, 2, 3, 4];
+        assert_eq!(percentile(&values, 0.0), 1.0);
+        assert_eq!(percentile(&values, 50.0), 2.5);
+        assert_eq!(percentile(&values, 100.0), 4.0);
+        assert_eq!(percentile(&[7], 90.0), 7.0);
+    }
+}
@@ -0,0 +1,144 @@
+use aoc_common::{parse, ParseError, Solution};
+use rules::Rules;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+pub mod rules;
+
+/// A letter of the strategy guide, one of `letters`.
+fn letter(s: &str, column: &str, letters: &BTreeSet<char>) -> Result<char, ParseError> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if letters.contains(&c) => Ok(c),
+        _ => {
+            let letters: Vec<_> = letters.iter().map(|c| format!("`{}`", c)).collect();
+            let expected = match letters.split_last() {
+                Some((last, [])) => last.clone(),
+                Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+                None => "letter, but the rules define none".to_string(),
+            };
+            Err(ParseError::new(format!("{} {}", column, expected), s))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Input {
+    /// The opponent's letter and the second letter of every round.
+    pub rounds: Vec<(char, char)>,
+}
+
+impl Input {
+    /// Parses a strategy guide of the letters `rules` give a meaning: the
+    /// opponent's hands in the first column, and hands or outcomes in the
+    /// second.
+    pub fn parse_with(s: &str, rules: &Rules) -> Result<Self, ParseError> {
+        let opponent = rules.opponent.keys().copied().collect();
+        let second = rules
+            .you
+            .keys()
+            .chain(rules.strategy.keys())
+            .copied()
+            .collect();
+        let rounds = s
+            .lines()
+            .map(|line| {
+                let (other, you) = parse::split_once(line, " ")?;
+                Ok((
+                    letter(other, "the opponent's", &opponent)?,
+                    letter(you, "your", &second)?,
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rounds })
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    /// Parses a strategy guide for the rules of the puzzle.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, puzzle_rules())
+    }
+}
+
+/// The rules of the puzzle, read once.
+fn puzzle_rules() -> &'static Rules {
+    static RULES: OnceLock<Rules> = OnceLock::new();
+    RULES.get_or_init(Rules::default)
+}
+
+// The puzzle gives every letter of the second column both a hand and an
+// outcome, so parsing has already checked all the letters either part needs.
+
+fn part1(input: &Input) -> impl Display {
+    puzzle_rules()
+        .play(&input.rounds)
+        .expect("letters are checked when parsing")
+}
+
+fn part2(input: &Input) -> impl Display {
+    puzzle_rules()
+        .follow(&input.rounds)
+        .expect("letters are checked when parsing")
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+A Y
+B X
+C Z";
+
+    #[test]
+    fn example_part1() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "15");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "12");
+    }
+
+    #[test]
+    fn unknown_letters() {
+        let s = "A Y\nD X";
+        let err = Day02::parse(s).unwrap_err().locate(s);
+        assert_eq!(err.expected(), "the opponent's `A`, `B` or `C`");
+        assert_eq!((err.line(), err.column()), (2, 1));
+        let err = Day02::parse("A W").unwrap_err();
+        assert_eq!(err.expected(), "your `X`, `Y` or `Z`");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day02>();
+    }
+}
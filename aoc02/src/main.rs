@@ -1,115 +1,78 @@
-use std::fmt::Display;
+use aoc02::rules::{self, Outcome, Reading, Rules};
+use aoc02::{Day02, Input};
+use std::path::PathBuf;
 
-#[derive(Copy, Clone, Debug)]
-enum Hand {
-    Rock,
-    Paper,
-    Scissor,
+#[derive(clap::Args)]
+struct Options {
+    /// Score the strategy guide by the rules in this TOML file instead
+    #[arg(long, value_name = "FILE")]
+    rules: Option<PathBuf>,
+    /// Score every way of reading the second column, as a different hand for
+    /// each letter and as any outcome for each letter, and show the best
+    #[arg(long)]
+    search: bool,
 }
 
-impl From<u8> for Hand {
-    fn from(s: u8) -> Self {
-        match s {
-            b'A' | b'X' => Hand::Rock,
-            b'B' | b'Y' => Hand::Paper,
-            b'C' | b'Z' => Hand::Scissor,
-            _ => unreachable!(),
-        }
-    }
+fn read_rules(path: &PathBuf) -> Result<Rules, String> {
+    let s = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    s.parse()
+        .map_err(|err| format!("invalid rules in {}: {}", path.display(), err))
 }
 
-impl Hand {
-    fn value(&self) -> usize {
-        match self {
-            Hand::Rock => 1,
-            Hand::Paper => 2,
-            Hand::Scissor => 3,
-        }
-    }
-
-    fn outcome(&self, other: &Hand) -> usize {
-        let score = match self {
-            Hand::Rock => match other {
-                Hand::Rock => 3,
-                Hand::Paper => 0,
-                Hand::Scissor => 6,
-            },
-            Hand::Paper => match other {
-                Hand::Rock => 6,
-                Hand::Paper => 3,
-                Hand::Scissor => 0,
-            },
-            Hand::Scissor => match other {
-                Hand::Rock => 0,
-                Hand::Paper => 6,
-                Hand::Scissor => 3,
-            },
-        };
-        score + self.value()
-    }
-
-    fn strategy(&self, other: &Hand) -> usize {
-        let hand = match self {
-            // Lose
-            Hand::Rock => match other {
-                Hand::Rock => Hand::Scissor,
-                Hand::Paper => Hand::Rock,
-                Hand::Scissor => Hand::Paper,
-            },
-            // Draw
-            Hand::Paper => match other {
-                Hand::Rock => Hand::Rock,
-                Hand::Paper => Hand::Paper,
-                Hand::Scissor => Hand::Scissor,
-            },
-            // Win
-            Hand::Scissor => match other {
-                Hand::Rock => Hand::Paper,
-                Hand::Paper => Hand::Scissor,
-                Hand::Scissor => Hand::Rock,
-            },
-        };
-        hand.outcome(other)
-    }
-}
-
-#[derive(Clone, Debug)]
-struct Input {
-    strategy: Vec<(Hand, Hand)>,
-}
-
-impl From<&'static str> for Input {
-    fn from(s: &'static str) -> Self {
-        let strategy = s
-            .lines()
-            .map(|line| {
-                let bytes = line.as_bytes();
-                (Hand::from(bytes[0]), Hand::from(bytes[2]))
-            })
+/// Lists `readings` with their scores, then the best of them.
+fn list<T>(readings: &[Reading<T>], name: impl Fn(&T) -> String) -> String {
+    let letters = |reading: &Reading<T>| {
+        let letters: Vec<_> = reading
+            .letters
+            .iter()
+            .map(|(letter, value)| format!("{}={}", letter, name(value)))
             .collect();
-        Self { strategy }
+        letters.join(" ")
+    };
+    let mut report = String::new();
+    for reading in readings {
+        report += &format!("  {}  {}\n", letters(reading), reading.score);
     }
-}
-
-fn part1(input: &Input) -> impl Display {
-    input
-        .strategy
-        .iter()
-        .map(|(other, you)| you.outcome(other))
-        .sum::<usize>()
-}
-
-fn part2(input: &Input) -> impl Display {
-    input
-        .strategy
-        .iter()
-        .map(|(other, you)| you.strategy(other))
-        .sum::<usize>()
+    if let Some(best) = rules::best(readings) {
+        report += &format!("best: {}  {}\n", letters(best), best.score);
+    }
+    report
 }
 
 fn main() {
-    let bytes = include_bytes!("input.txt");
-    let input: Input = std::str::from_utf8(bytes).unwrap().into();
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
+    aoc_common::main_with::<Day02, Options>(|options, args, source| {
+        if options.rules.is_none() && !options.search {
+            return None;
+        }
+        let score = || {
+            let rules = match &options.rules {
+                Some(path) => read_rules(path)?,
+                None => Rules::default(),
+            };
+            let s = source.read()?;
+            let input = Input::parse_with(&s, &rules).map_err(|err| err.locate(&s).diagnostic())?;
+            let mut report = String::new();
+            if args.part != Some(2) {
+                if options.search {
+                    let readings = rules.hand_readings(&input.rounds)?;
+                    report += "part1, the second column as hands:\n";
+                    report += &list(&readings, |&hand| rules.hands()[hand].clone());
+                } else {
+                    report += &format!("part1: {}\n", rules.play(&input.rounds)?);
+                }
+            }
+            if args.part != Some(1) {
+                if options.search {
+                    let readings = rules.outcome_readings(&input.rounds)?;
+                    report += "part2, the second column as outcomes:\n";
+                    report += &list(&readings, Outcome::to_string);
+                } else {
+                    report += &format!("part2: {}\n", rules.follow(&input.rounds)?);
+                }
+            }
+            Ok(report)
+        };
+        Some(score())
+    });
 }
@@ -0,0 +1,420 @@
+//! Any cyclic hand game, defined by data rather than code.
+//!
+//! A game is a set of hands, which hand beats which, and how many points
+//! each hand and each outcome are worth. On top of that come the letters of
+//! the strategy guide: which hand the opponent's letters stand for, and
+//! what the second column means in either part. See
+//! `rules/rock-paper-scissors.toml` for the format.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The rules of the puzzle itself.
+const ROCK_PAPER_SCISSORS: &str = include_str!("../rules/rock-paper-scissors.toml");
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Lose => write!(f, "lose"),
+            Outcome::Draw => write!(f, "draw"),
+            Outcome::Win => write!(f, "win"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OutcomePoints {
+    lose: usize,
+    draw: usize,
+    win: usize,
+}
+
+impl Default for OutcomePoints {
+    fn default() -> Self {
+        Self {
+            lose: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+/// The rules as written, with hands by name.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Spec {
+    hands: Vec<String>,
+    points: Option<Vec<usize>>,
+    #[serde(default)]
+    outcomes: OutcomePoints,
+    beats: BTreeMap<String, Vec<String>>,
+    opponent: BTreeMap<char, String>,
+    you: BTreeMap<char, String>,
+    strategy: BTreeMap<char, Outcome>,
+}
+
+/// A hand game and how to read a strategy guide for it. Hands are indices
+/// into the game's list of hands.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    hands: Vec<String>,
+    points: Vec<usize>,
+    /// Indexed by [`Outcome`].
+    outcome_points: [usize; 3],
+    /// `beats[a][b]` when hand `a` beats hand `b`.
+    beats: Vec<Vec<bool>>,
+    pub opponent: BTreeMap<char, usize>,
+    pub you: BTreeMap<char, usize>,
+    pub strategy: BTreeMap<char, Outcome>,
+}
+
+impl FromStr for Rules {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let spec: Spec = toml::from_str(s).map_err(|err| err.to_string())?;
+        let n = spec.hands.len();
+        if n == 0 {
+            return Err("a game needs at least one hand".to_string());
+        }
+        let hand = |name: &str| {
+            spec.hands
+                .iter()
+                .position(|hand| hand == name)
+                .ok_or_else(|| format!("unknown hand `{}`", name))
+        };
+        for (i, name) in spec.hands.iter().enumerate() {
+            if hand(name)? != i {
+                return Err(format!("hand `{}` is listed twice", name));
+            }
+        }
+
+        let points = spec.points.unwrap_or_else(|| (1..=n).collect());
+        if points.len() != n {
+            return Err(format!("{} hands but {} points", n, points.len()));
+        }
+
+        let mut beats = vec![vec![false; n]; n];
+        for (winner, losers) in &spec.beats {
+            let winner = hand(winner)?;
+            for loser in losers {
+                beats[winner][hand(loser)?] = true;
+            }
+        }
+        for (a, name) in spec.hands.iter().enumerate() {
+            if beats[a][a] {
+                return Err(format!("`{}` cannot beat itself", name));
+            }
+            for (b, other) in spec.hands.iter().enumerate().skip(a + 1) {
+                if beats[a][b] == beats[b][a] {
+                    return Err(format!(
+                        "exactly one of `{}` and `{}` has to beat the other",
+                        name, other
+                    ));
+                }
+            }
+        }
+        // Otherwise some outcome could not be had against that hand.
+        for (a, name) in spec.hands.iter().enumerate() {
+            if !(0..n).any(|b| beats[a][b]) || !(0..n).any(|b| beats[b][a]) {
+                return Err(format!(
+                    "every hand must beat at least one hand and lose to at least one, \
+                     but `{}` does not",
+                    name
+                ));
+            }
+        }
+
+        let letters = |column: &BTreeMap<char, String>| {
+            column
+                .iter()
+                .map(|(&letter, name)| Ok((letter, hand(name)?)))
+                .collect::<Result<BTreeMap<_, _>, String>>()
+        };
+        let outcomes = spec.outcomes;
+        Ok(Self {
+            points,
+            outcome_points: [outcomes.lose, outcomes.draw, outcomes.win],
+            beats,
+            opponent: letters(&spec.opponent)?,
+            you: letters(&spec.you)?,
+            strategy: spec.strategy,
+            hands: spec.hands,
+        })
+    }
+}
+
+/// One way to read the letters of the second column, and what the guide
+/// scores read that way.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reading<T> {
+    pub letters: BTreeMap<char, T>,
+    pub score: usize,
+}
+
+/// Every way to give `k` letters different values out of `values`, in
+/// lexicographic order.
+fn arrangements<T: Copy>(values: &[T], k: usize) -> Vec<Vec<T>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+    let mut all = Vec::new();
+    for (i, &first) in values.iter().enumerate() {
+        let mut rest = values.to_vec();
+        rest.remove(i);
+        for mut arrangement in arrangements(&rest, k - 1) {
+            arrangement.insert(0, first);
+            all.push(arrangement);
+        }
+    }
+    all
+}
+
+/// The most letters to try every outcome for, which is already `3^10` ways.
+const MAX_OUTCOME_LETTERS: usize = 10;
+
+/// Every way to give `k` letters values out of `values`, letters sharing
+/// values allowed, in lexicographic order.
+fn mappings<T: Copy>(values: &[T], k: usize) -> Vec<Vec<T>> {
+    (0..k).fold(vec![Vec::new()], |mappings, _| {
+        mappings
+            .iter()
+            .flat_map(|mapping| {
+                values.iter().map(move |&value| {
+                    let mut mapping = mapping.clone();
+                    mapping.push(value);
+                    mapping
+                })
+            })
+            .collect()
+    })
+}
+
+/// The reading that scores most, the first of them on a tie.
+pub fn best<T>(readings: &[Reading<T>]) -> Option<&Reading<T>> {
+    readings.iter().rev().max_by_key(|reading| reading.score)
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        ROCK_PAPER_SCISSORS.parse().unwrap()
+    }
+}
+
+impl Rules {
+    pub fn hands(&self) -> &[String] {
+        &self.hands
+    }
+
+    pub fn outcome(&self, you: usize, other: usize) -> Outcome {
+        if self.beats[you][other] {
+            Outcome::Win
+        } else if self.beats[other][you] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// Your score for playing `you` against `other`.
+    pub fn score(&self, you: usize, other: usize) -> usize {
+        self.points[you] + self.outcome_points[self.outcome(you, other) as usize]
+    }
+
+    /// The hand to play against `other` for `outcome`. When several hands
+    /// would do, the one that scores most. There is always one, as every
+    /// hand beats some hand and loses to another.
+    pub fn respond(&self, other: usize, outcome: Outcome) -> usize {
+        (0..self.hands.len())
+            .filter(|&you| self.outcome(you, other) == outcome)
+            .max_by_key(|&you| (self.points[you], std::cmp::Reverse(you)))
+            .expect("every hand beats one and loses to one")
+    }
+
+    fn opponent(&self, letter: char) -> Result<usize, String> {
+        self.opponent
+            .get(&letter)
+            .copied()
+            .ok_or_else(|| format!("no hand for the opponent's `{}`", letter))
+    }
+
+    /// The total score when the second column says which hand to play.
+    pub fn play(&self, rounds: &[(char, char)]) -> Result<usize, String> {
+        rounds
+            .iter()
+            .map(|&(other, you)| {
+                let you = self
+                    .you
+                    .get(&you)
+                    .ok_or_else(|| format!("no hand for your `{}`", you))?;
+                Ok(self.score(*you, self.opponent(other)?))
+            })
+            .sum()
+    }
+
+    /// The total score when the second column says how the round has to
+    /// end.
+    pub fn follow(&self, rounds: &[(char, char)]) -> Result<usize, String> {
+        rounds
+            .iter()
+            .map(|&(other, outcome)| {
+                let outcome = self
+                    .strategy
+                    .get(&outcome)
+                    .ok_or_else(|| format!("no outcome for `{}`", outcome))?;
+                let other = self.opponent(other)?;
+                Ok(self.score(self.respond(other, *outcome), other))
+            })
+            .sum()
+    }
+
+    /// Every reading of the second column as hands to play, each letter a
+    /// different hand, scored as in part 1.
+    pub fn hand_readings(&self, rounds: &[(char, char)]) -> Result<Vec<Reading<usize>>, String> {
+        let letters: Vec<char> = self.you.keys().copied().collect();
+        let hands: Vec<usize> = (0..self.hands.len()).collect();
+        if letters.len() > hands.len() {
+            return Err(format!(
+                "{} letters for {} hands",
+                letters.len(),
+                hands.len()
+            ));
+        }
+        arrangements(&hands, letters.len())
+            .into_iter()
+            .map(|arrangement| {
+                let mut rules = self.clone();
+                rules.you = letters.iter().copied().zip(arrangement).collect();
+                Ok(Reading {
+                    score: rules.play(rounds)?,
+                    letters: rules.you,
+                })
+            })
+            .collect()
+    }
+
+    /// Every reading of the second column as outcomes, scored as in part 2.
+    /// Unlike hands, letters may share an outcome, so `k` letters have
+    /// `3^k` readings.
+    pub fn outcome_readings(
+        &self,
+        rounds: &[(char, char)],
+    ) -> Result<Vec<Reading<Outcome>>, String> {
+        let letters: Vec<char> = self.strategy.keys().copied().collect();
+        if letters.len() > MAX_OUTCOME_LETTERS {
+            return Err(format!(
+                "{} letters have too many readings as outcomes, the most is {}",
+                letters.len(),
+                MAX_OUTCOME_LETTERS
+            ));
+        }
+        mappings(&Outcome::ALL, letters.len())
+            .into_iter()
+            .map(|arrangement| {
+                let mut rules = self.clone();
+                rules.strategy = letters.iter().copied().zip(arrangement).collect();
+                Ok(Reading {
+                    score: rules.follow(rounds)?,
+                    letters: rules.strategy,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rock_paper_scissors() {
+        let rules = Rules::default();
+        let [rock, paper, scissors] = [0, 1, 2];
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, paper), Outcome::Lose);
+        assert_eq!(rules.outcome(scissors, scissors), Outcome::Draw);
+        assert_eq!(rules.respond(rock, Outcome::Lose), scissors);
+    }
+
+    #[test]
+    fn lizard_spock() {
+        let rules: Rules = include_str!("../rules/rock-paper-scissors-lizard-spock.toml")
+            .parse()
+            .unwrap();
+        let [rock, _, scissors, lizard, spock] = [0, 1, 2, 3, 4];
+        assert_eq!(rules.outcome(spock, scissors), Outcome::Win);
+        assert_eq!(rules.outcome(lizard, spock), Outcome::Win);
+        // Both paper and Spock beat rock, and Spock scores more.
+        assert_eq!(rules.respond(rock, Outcome::Win), spock);
+        assert_eq!(rules.play(&[('A', 'Z'), ('E', 'Y')]), Ok(5 + 6 + 4 + 6));
+    }
+
+    #[test]
+    fn all_readings() {
+        assert_eq!(arrangements(&[1, 2, 3], 3).len(), 6);
+        assert_eq!(arrangements(&[1, 2, 3, 4, 5], 3).len(), 60);
+        assert_eq!(arrangements(&[1, 2], 2), [[1, 2], [2, 1]]);
+
+        let rules = Rules::default();
+        let rounds = [('A', 'Y'), ('B', 'X'), ('C', 'Z')];
+        let readings = rules.hand_readings(&rounds).unwrap();
+        assert_eq!(readings.len(), 6);
+        let puzzle = readings
+            .iter()
+            .find(|reading| reading.letters == rules.you)
+            .unwrap();
+        assert_eq!(puzzle.score, 15);
+        assert_eq!(mappings(&[1, 2], 2), [[1, 1], [1, 2], [2, 1], [2, 2]]);
+        let outcomes = rules.outcome_readings(&rounds).unwrap();
+        assert_eq!(outcomes.len(), 27);
+        // Every letter comes up once, so best is to win every round.
+        let best = best(&outcomes).unwrap();
+        assert_eq!(best.score, 3 * 6 + 2 + 3 + 1);
+        assert!(best
+            .letters
+            .values()
+            .all(|&outcome| outcome == Outcome::Win));
+    }
+
+    #[test]
+    fn invalid_rules() {
+        let rules = ROCK_PAPER_SCISSORS.replace("paper = [\"rock\"]", "paper = []");
+        assert_eq!(
+            rules.parse::<Rules>(),
+            Err("exactly one of `rock` and `paper` has to beat the other".to_string())
+        );
+        let rules = ROCK_PAPER_SCISSORS.replace("C = \"scissors\"", "C = \"spock\"");
+        assert_eq!(
+            rules.parse::<Rules>(),
+            Err("unknown hand `spock`".to_string())
+        );
+        let rules = "hands = [\"rock\", \"paper\"]\n\
+                     [beats]\nrock = [\"paper\"]\n\
+                     [opponent]\n[you]\n[strategy]";
+        assert_eq!(
+            rules.parse::<Rules>(),
+            Err(
+                "every hand must beat at least one hand and lose to at least one, \
+                 but `rock` does not"
+                    .to_string()
+            )
+        );
+        let rules = "hands = [\"rock\"]\n[beats]\n[opponent]\n[you]\n[strategy]";
+        assert!(rules.parse::<Rules>().is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_common::item_set::{priority, ItemSet};
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Input {
+    rucksacks: Vec<Vec<u8>>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rucksacks = s
+            .split('\n')
+            .map(|line| {
+                if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                    return Err(ParseError::new(
+                        "an item `a`-`z` or `A`-`Z`",
+                        &line[i..i + c.len_utf8()],
+                    ));
+                }
+                if line.len() % 2 != 0 {
+                    return Err(ParseError::new("an even number of items", line));
+                }
+                Ok(line.as_bytes().to_vec())
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rucksacks })
+    }
+}
+
+/// The priority of the one item in `set`.
+fn only_priority(set: ItemSet) -> usize {
+    let mut items = set.iter();
+    match (items.next(), items.next()) {
+        (Some(item), None) => priority(item).unwrap() as usize,
+        _ => panic!("expected a single shared item, found {:?}", set),
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    input
+        .rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+            ItemSet::from_bytes(first) & ItemSet::from_bytes(second)
+        })
+        .map(only_priority)
+        .sum()
+}
+
+fn part2(input: &Input) -> usize {
+    input
+        .rucksacks
+        .chunks_exact(3)
+        .map(|group| {
+            group
+                .iter()
+                .map(|rucksack| ItemSet::from_bytes(rucksack))
+                .reduce(|badge, rucksack| badge & rucksack)
+                .unwrap()
+        })
+        .map(only_priority)
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn example_part1() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "157");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "70");
+    }
+
+    #[test]
+    fn non_ascii_items() {
+        let s = "abcd\nabé!";
+        let err = Day03::parse(s).unwrap_err().locate(s);
+        assert_eq!(err.found(), "é");
+        assert_eq!((err.line(), err.column()), (2, 3));
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day03>();
+    }
+}
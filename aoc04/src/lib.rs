@@ -0,0 +1,115 @@
+use aoc_common::{parse, ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug)]
+struct Section(u32, u32);
+
+impl FromStr for Section {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = parse::split_once(s, "-")?;
+        Ok(Self(parse::number(left)?, parse::number(right)?))
+    }
+}
+
+impl Section {
+    fn fully_contains(&self, other: &Section) -> bool {
+        self.0 <= other.0 && other.1 <= self.1
+    }
+
+    fn disjoint(&self, other: &Section) -> bool {
+        if self.0 < other.0 {
+            self.0 < other.0 && self.1 < other.0
+        } else {
+            other.0 < self.0 && other.1 < self.0
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    section_pairs: Vec<(Section, Section)>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let section_pairs = s
+            .split('\n')
+            .map(|line| {
+                let (lhs, rhs) = parse::split_once(line, ",")?;
+                Ok((lhs.parse()?, rhs.parse()?))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { section_pairs })
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    input
+        .section_pairs
+        .iter()
+        .filter(|&(lhs, rhs)| lhs.fully_contains(rhs) || rhs.fully_contains(lhs))
+        .count()
+}
+
+fn part2(input: &Input) -> usize {
+    input
+        .section_pairs
+        .iter()
+        .filter(|&(lhs, rhs)| !lhs.disjoint(rhs))
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    #[test]
+    fn example_part1() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "2");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "4");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day04>();
+    }
+}
@@ -0,0 +1,201 @@
+use aoc_common::{parse, ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug)]
+struct Move {
+    num: usize,
+    from: usize,
+    to: usize,
+}
+
+/// The index of a stack numbered from 1, one of `stacks` stacks.
+fn stack_index(s: &str, stacks: usize) -> Result<usize, ParseError> {
+    match parse::number::<usize>(s)? {
+        n if (1..=stacks).contains(&n) => Ok(n - 1),
+        _ => Err(ParseError::new(
+            format!("a stack number from 1 to {}", stacks),
+            s,
+        )),
+    }
+}
+
+impl Move {
+    /// Parses a move between stacks of `heights` crates, which it then
+    /// updates, so that no move takes more crates than a stack holds.
+    fn parse(s: &str, heights: &mut [usize]) -> Result<Self, ParseError> {
+        let s = parse::prefix(s, "move ")?;
+        let (num, s) = parse::split_once(s, " from ")?;
+        let (from, to) = parse::split_once(s, " to ")?;
+        let m = Self {
+            num: parse::number(num)?,
+            from: stack_index(from, heights.len())?,
+            to: stack_index(to, heights.len())?,
+        };
+        if m.num > heights[m.from] {
+            return Err(ParseError::new(
+                format!("at most the {} crates on stack {}", heights[m.from], from),
+                num,
+            ));
+        }
+        heights[m.from] -= m.num;
+        heights[m.to] += m.num;
+        Ok(m)
+    }
+}
+
+type Stack = Vec<u8>;
+
+#[derive(Debug)]
+pub struct Input {
+    stacks: Vec<Stack>,
+    moves: Vec<Move>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (init, moves) = parse::split_once(s, "\n\n")?;
+
+        let mut init_iter = init.split('\n').rev();
+        let numbers = parse::next(&mut init_iter, "a line of stack numbers", init)?;
+        let num_stacks = numbers.split_ascii_whitespace().count();
+        if num_stacks == 0 {
+            return Err(ParseError::new("a line of stack numbers", numbers));
+        }
+        let mut stacks = vec![Stack::new(); num_stacks];
+        for line in init_iter {
+            if line.len() < num_stacks * 4 - 1 {
+                return Err(ParseError::end(
+                    format!("{} stacks of crates", num_stacks),
+                    line,
+                ));
+            }
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                let c = line.as_bytes()[i * 4 + 1];
+                if c != b' ' {
+                    stack.push(c);
+                }
+            }
+        }
+
+        let mut heights: Vec<usize> = stacks.iter().map(Stack::len).collect();
+        let moves = moves
+            .split('\n')
+            .map(|line| Move::parse(line, &mut heights))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { stacks, moves })
+    }
+}
+
+fn part1(input: &Input) -> String {
+    input
+        .moves
+        .iter()
+        .fold(input.stacks.clone(), |mut stacks, m| {
+            let from = &mut stacks[m.from];
+            let tail: Vec<_> = from.iter().copied().rev().take(m.num).collect();
+            from.resize(from.len() - m.num, 0);
+            let to = &mut stacks[m.to];
+            to.extend(tail);
+            stacks
+        })
+        .into_iter()
+        // An empty stack has no crate on top to show.
+        .map(|stack| stack.last().map_or(' ', |&c| c as char))
+        .collect()
+}
+
+fn part2(input: &Input) -> String {
+    input
+        .moves
+        .iter()
+        .fold(input.stacks.clone(), |mut stacks, m| {
+            let from = &mut stacks[m.from];
+            let tail: Vec<_> = from.iter().copied().skip(from.len() - m.num).collect();
+            from.resize(from.len() - m.num, 0);
+            let to = &mut stacks[m.to];
+            to.extend(tail);
+            stacks
+        })
+        .into_iter()
+        .map(|stack| stack.last().map_or(' ', |&c| c as char))
+        .collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "    [D]    \n",
+        "[N] [C]    \n",
+        "[Z] [M] [P]\n",
+        " 1   2   3 \n",
+        "\n",
+        "move 1 from 2 to 1\n",
+        "move 3 from 1 to 3\n",
+        "move 2 from 2 to 1\n",
+        "move 1 from 1 to 2",
+    );
+
+    #[test]
+    fn example_part1() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "CMZ");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "MCD");
+    }
+
+    #[test]
+    fn unknown_stack() {
+        let s = EXAMPLE.replace("move 1 from 1 to 2", "move 1 from 1 to 7");
+        let err = Day05::parse(&s).unwrap_err().locate(&s);
+        assert_eq!(err.expected(), "a stack number from 1 to 3");
+        assert_eq!(err.found(), "7");
+    }
+
+    #[test]
+    fn impossible_stacks() {
+        let s = "[A]\n   \n\nmove 1 from 1 to 1";
+        let err = Day05::parse(s).unwrap_err().locate(s);
+        assert_eq!(err.expected(), "a line of stack numbers");
+        assert_eq!((err.line(), err.column()), (2, 1));
+
+        // Stack 2 holds 2 crates by then.
+        let s = EXAMPLE.replace("move 2 from 2 to 1", "move 4 from 2 to 1");
+        let err = Day05::parse(&s).unwrap_err().locate(&s);
+        assert_eq!(err.expected(), "at most the 2 crates on stack 2");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day05>();
+    }
+}
@@ -0,0 +1,93 @@
+use aoc_common::item_set::ItemSet;
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Input {
+    signal: Vec<u8>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((i, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(ParseError::new("a letter `a`-`z`", &s[i..i + c.len_utf8()]));
+        }
+        let signal = s.as_bytes().to_vec();
+
+        Ok(Self { signal })
+    }
+}
+
+/// How many characters come in up to the end of the first run of `len`
+/// different ones.
+fn marker(signal: &[u8], len: usize) -> usize {
+    signal
+        .windows(len)
+        .position(|window| ItemSet::from_bytes(window).len() == len)
+        .expect("no marker in the signal")
+        + len
+}
+
+fn part1(input: &Input) -> usize {
+    marker(&input.signal, 4)
+}
+
+fn part2(input: &Input) -> usize {
+    marker(&input.signal, 14)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+    #[test]
+    fn example_part1() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "7");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "19");
+    }
+
+    #[test]
+    fn non_ascii_letters() {
+        let s = "abcdé";
+        let err = Day06::parse(s).unwrap_err().locate(s);
+        assert_eq!(err.found(), "é");
+        assert_eq!(err.column(), 5);
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day06>();
+    }
+}
@@ -0,0 +1,254 @@
+use aoc_common::{parse, ParseError, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Clone, Debug)]
+enum Command {
+    Cd(String),
+    Ls,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(dir) = s.strip_prefix("cd ") {
+            Ok(Command::Cd(dir.to_string()))
+        } else if s == "ls" {
+            Ok(Command::Ls)
+        } else {
+            Err(ParseError::new("`cd <dir>` or `ls`", s))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Output {
+    Dir(String),
+    File(String, usize),
+}
+
+impl FromStr for Output {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse::split_once(s, " ")? {
+            ("dir", dir) => Ok(Output::Dir(dir.to_string())),
+            (size, file) => Ok(Output::File(file.to_string(), parse::number(size)?)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    command_pairs: Vec<(Command, Vec<Output>)>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut line_iter = s.split('\n').peekable();
+        let mut command_pairs = Vec::new();
+        while let Some(command) = line_iter.next() {
+            let command: Command = parse::prefix(command, "$ ")?.parse()?;
+            let mut outputs = Vec::new();
+            while let Some(output) = line_iter.next_if(|&line| !line.starts_with('$')) {
+                outputs.push(output.parse()?);
+            }
+            command_pairs.push((command, outputs));
+        }
+
+        Ok(Self { command_pairs })
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    let mut files: HashSet<PathBuf> = HashSet::new();
+    let mut sizes: HashMap<PathBuf, usize> = HashMap::new();
+    let mut curr_dir: PathBuf = PathBuf::new();
+    for (command, outputs) in &input.command_pairs {
+        //println!("{:?} -> {:?}", command, outputs);
+        match command {
+            Command::Cd(dir) => {
+                if *dir == "/" {
+                    curr_dir = PathBuf::new();
+                    curr_dir.push("/")
+                } else if *dir == ".." {
+                    curr_dir.pop();
+                } else {
+                    curr_dir.push(dir);
+                }
+            }
+            Command::Ls => {
+                for output in outputs {
+                    match output {
+                        Output::Dir(_dir) => {
+                            //parents.insert(dir, curr_dir);
+                        }
+                        Output::File(file, size) => {
+                            let file = curr_dir.as_path().join(file).to_owned();
+                            files.insert(file.clone());
+                            sizes.insert(file, *size);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut stack: VecDeque<PathBuf> = files.iter().cloned().collect();
+    let mut visited: HashSet<PathBuf> = HashSet::new();
+    while let Some(path) = stack.pop_front() {
+        if !visited.insert(path.clone()) {
+            continue;
+        }
+        let mut parent = path.clone();
+        if parent.pop() {
+            let size = *sizes.get(&path).unwrap();
+            sizes
+                .entry(parent.clone())
+                .and_modify(|e| {
+                    *e += size;
+                })
+                .or_insert(size);
+            stack.push_back(parent);
+        }
+    }
+
+    sizes
+        .iter()
+        .filter_map(|(item, &size)| {
+            if files.contains(item) || size > 100000 {
+                None
+            } else {
+                Some(size)
+            }
+        })
+        .sum()
+}
+
+fn part2(input: &Input) -> usize {
+    let root = Path::new("/").to_owned();
+
+    let mut visit: Vec<PathBuf> = Vec::new();
+    let mut all_children: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
+    let mut sizes: HashMap<PathBuf, usize> = HashMap::new();
+    let mut curr_dir: PathBuf = PathBuf::new();
+    for (command, outputs) in &input.command_pairs {
+        match command {
+            Command::Cd(dir) => {
+                if *dir == "/" {
+                    curr_dir = root.clone();
+                } else if *dir == ".." {
+                    curr_dir.pop();
+                } else {
+                    curr_dir.push(dir);
+                }
+            }
+            Command::Ls => {
+                visit.push(curr_dir.clone());
+                let total_size = sizes.entry(curr_dir.clone()).or_insert(0);
+                let children = all_children.entry(curr_dir.clone()).or_default();
+                for output in outputs {
+                    match output {
+                        Output::Dir(dir) => {
+                            let dir = curr_dir.as_path().join(dir).to_owned();
+                            children.push(dir);
+                        }
+                        Output::File(_file, size) => {
+                            *total_size += *size;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    while let Some(path) = visit.pop() {
+        let children = all_children.get(&path).unwrap();
+        let add_size: usize = children.iter().map(|child| sizes.get(child).unwrap()).sum();
+        let total_size = sizes.entry(path).or_insert(0);
+        *total_size += add_size;
+    }
+
+    let total_size = sizes.get(&root).unwrap();
+    let free_space = 70_000_000 - total_size;
+    let delete_at_least = 30_000_000 - free_space;
+
+    sizes
+        .values()
+        .copied()
+        .filter(|&size| size >= delete_at_least)
+        .min()
+        .unwrap()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn example_part1() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "95437");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "24933642");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day07>();
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_common::grid::{Grid, DIRECTIONS4};
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Input {
+    map: Grid<u32>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(s, "a tree height `0`-`9`", |c| c.to_digit(10))?;
+        Ok(Self { map })
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    let map = &input.map;
+    map.indexed_iter()
+        .filter(|&(ind, &c)| {
+            DIRECTIONS4
+                .iter()
+                .any(|&dir| map.ray(ind, dir).all(|(_, &t)| t < c))
+        })
+        .count()
+}
+
+fn see_trees<'a>(view: impl Iterator<Item = &'a u32>, base: u32) -> u32 {
+    let mut summa = 0;
+    for t in view.copied() {
+        summa += 1;
+        if t >= base {
+            break;
+        }
+    }
+    summa
+}
+
+fn part2(input: &Input) -> u32 {
+    let map = &input.map;
+    map.indexed_iter()
+        .map(|(ind, &c)| {
+            DIRECTIONS4
+                .iter()
+                .map(|&dir| see_trees(map.ray(ind, dir).map(|(_, t)| t), c))
+                .product()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn example_part1() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "21");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "8");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day08>();
+    }
+}
@@ -0,0 +1,245 @@
+use aoc_common::grid::Pos;
+use aoc_common::visualize::{Cell, Frame, Rgb, Sink};
+use aoc_common::{parse, ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug)]
+enum Step {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Step::Left),
+            "R" => Ok(Step::Right),
+            "U" => Ok(Step::Up),
+            "D" => Ok(Step::Down),
+            _ => Err(ParseError::new("one of `L`, `R`, `U` or `D`", s)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    steps: Vec<(Step, i32)>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps = s
+            .lines()
+            .map(|v| {
+                let (step, len) = parse::split_once(v, " ")?;
+                let step = step.parse()?;
+                let len = parse::number(len)?;
+                Ok((step, len))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { steps })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Knot {
+    coord: (i32, i32),
+}
+
+impl Knot {
+    fn new() -> Self {
+        Self { coord: (0, 0) }
+    }
+
+    fn step(&mut self, step: Step) {
+        match step {
+            Step::Left => self.coord.0 -= 1,
+            Step::Right => self.coord.0 += 1,
+            Step::Up => self.coord.1 += 1,
+            Step::Down => self.coord.1 -= 1,
+        }
+    }
+
+    fn follow(&mut self, parent: Knot) {
+        let x_diff = parent.coord.0 - self.coord.0;
+        let y_diff = parent.coord.1 - self.coord.1;
+        match (x_diff.abs(), y_diff.abs()) {
+            (0, 0) | (1, 0) | (0, 1) | (1, 1) => {}
+            (_, 0) => {
+                self.coord.0 += x_diff.signum();
+            }
+            (0, _) => {
+                self.coord.1 += y_diff.signum();
+            }
+            (_, _) => {
+                self.coord.0 += x_diff.signum();
+                self.coord.1 += y_diff.signum();
+            }
+        }
+    }
+}
+
+/// Moves a rope of `num` knots through `steps`, calling `on_step` after
+/// every single move, and returns how many positions the tail visited.
+fn simulate_knots(
+    steps: &[(Step, i32)],
+    num: usize,
+    mut on_step: impl FnMut(&[Knot], &HashSet<Knot>),
+) -> usize {
+    let mut tail_visit: HashSet<Knot> = HashSet::new();
+    let mut knots: Vec<Knot> = vec![Knot::new(); num];
+    tail_visit.insert(Knot::new());
+    for &(step, len) in steps {
+        for _ in 0..len {
+            knots[0].step(step);
+            for i in 1..knots.len() {
+                let parent = knots[i - 1];
+                knots[i].follow(parent);
+            }
+            let tail = knots[knots.len() - 1];
+            tail_visit.insert(tail);
+            on_step(&knots, &tail_visit);
+        }
+    }
+    tail_visit.len()
+}
+
+/// The rope and the tail's trail, within the area the head ever covers.
+struct Rope<'a> {
+    knots: &'a [Knot],
+    visited: &'a HashSet<Knot>,
+    min: (i32, i32),
+    max: (i32, i32),
+}
+
+impl Rope<'_> {
+    /// The cell position of `coord`, with up being +y.
+    fn pos(&self, coord: (i32, i32)) -> Pos {
+        (
+            (self.max.1 - coord.1) as usize,
+            (coord.0 - self.min.0) as usize,
+        )
+    }
+}
+
+impl Frame for Rope<'_> {
+    fn width(&self) -> usize {
+        (self.max.0 - self.min.0 + 1) as usize
+    }
+
+    fn height(&self) -> usize {
+        (self.max.1 - self.min.1 + 1) as usize
+    }
+
+    fn cell(&self, pos: Pos) -> Cell {
+        let coord = (self.min.0 + pos.1 as i32, self.max.1 - pos.0 as i32);
+        let last = self.knots.len() - 1;
+        if let Some(i) = self.knots.iter().position(|knot| knot.coord == coord) {
+            let glyph = match i {
+                0 => 'H',
+                i if i == last => 'T',
+                i => char::from_digit(i as u32, 10).unwrap(),
+            };
+            let t = i as f32 / last as f32;
+            Cell::new(glyph, Rgb(255, 90, 60).lerp(Rgb(250, 220, 90), t))
+        } else if coord == (0, 0) {
+            Cell::new('s', Rgb::WHITE)
+        } else if self.visited.contains(&Knot { coord }) {
+            Cell::new('#', Rgb::GREY)
+        } else {
+            Cell::BLANK
+        }
+    }
+
+    fn focus(&self) -> Option<Pos> {
+        Some(self.pos(self.knots[0].coord))
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    simulate_knots(input.steps.as_slice(), 2, |_, _| {})
+}
+
+fn part2(input: &Input) -> usize {
+    simulate_knots(input.steps.as_slice(), 10, |_, _| {})
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, part: u8, sink: &mut dyn Sink) {
+        let num = if part == 1 { 2 } else { 10 };
+        // Every knot trails the head, so the head's range bounds them all.
+        let (mut min, mut max) = ((0, 0), (0, 0));
+        simulate_knots(&input.steps, 1, |knots, _| {
+            let (x, y) = knots[0].coord;
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        });
+        simulate_knots(&input.steps, num, |knots, visited| {
+            sink.frame(&Rope {
+                knots,
+                visited,
+                min,
+                max,
+            })
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    #[test]
+    fn example_part1() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "13");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "1");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day09>();
+    }
+}
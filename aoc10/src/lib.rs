@@ -0,0 +1,378 @@
+use aoc_common::grid::Pos;
+use aoc_common::visualize::{Cell, Frame, Rgb, Sink};
+use aoc_common::{parse, ParseError, Solution};
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug)]
+enum Instr {
+    Add(i32),
+    Noop,
+}
+
+impl FromStr for Instr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "noop" {
+            Ok(Instr::Noop)
+        } else {
+            let n = parse::number(parse::prefix(s, "addx ")?)?;
+            Ok(Instr::Add(n))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    instructions: Vec<Instr>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let instructions = s.lines().map(str::parse).collect::<Result<_, _>>()?;
+
+        Ok(Self { instructions })
+    }
+}
+
+struct SignalStrength {
+    cycles: usize,
+    score: usize,
+    check_at: usize,
+}
+
+impl SignalStrength {
+    fn new() -> Self {
+        SignalStrength {
+            cycles: 0,
+            score: 0,
+            check_at: 20,
+        }
+    }
+
+    fn advance(&mut self, x: i32) {
+        self.cycles += 1;
+        if self.cycles == self.check_at {
+            let score = self.check_at * x as usize;
+            self.score += score;
+            self.check_at += 40;
+        }
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    let mut x: i32 = 1;
+    let mut signal_strength = SignalStrength::new();
+    for instr in &input.instructions {
+        match instr {
+            Instr::Noop => {
+                signal_strength.advance(x);
+            }
+            Instr::Add(n) => {
+                signal_strength.advance(x);
+                signal_strength.advance(x);
+                x += n;
+            }
+        }
+    }
+    signal_strength.score
+}
+
+struct Crt {
+    pixels: [[char; 40]; 6],
+    cursor: (usize, usize),
+}
+
+impl Crt {
+    fn new() -> Self {
+        Crt {
+            pixels: [[' '; 40]; 6],
+            cursor: (0, 0),
+        }
+    }
+
+    fn draw(&mut self, sprite: i32) {
+        if (self.cursor.0 as i32 - sprite).abs() <= 1 {
+            self.pixels[self.cursor.1][self.cursor.0] = '█';
+        }
+        self.cursor.0 += 1;
+        if self.cursor.0 == 40 {
+            self.cursor.0 = 0;
+            self.cursor.1 += 1;
+        }
+    }
+}
+
+impl std::fmt::Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (ind, col) in self.pixels.iter().enumerate() {
+            if ind != 0 {
+                f.write_char('\n')?;
+            }
+            let row: String = col.iter().collect();
+            f.write_str(&row)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs the program on a fresh CRT, calling `on_draw` with the sprite
+/// position after every pixel drawn.
+fn draw_crt(input: &Input, mut on_draw: impl FnMut(&Crt, i32)) -> Crt {
+    let mut crt = Crt::new();
+    let mut x: i32 = 1;
+    for instr in &input.instructions {
+        match instr {
+            Instr::Noop => {
+                crt.draw(x);
+                on_draw(&crt, x);
+            }
+            Instr::Add(n) => {
+                crt.draw(x);
+                on_draw(&crt, x);
+                crt.draw(x);
+                on_draw(&crt, x);
+                x += n;
+            }
+        }
+    }
+    crt
+}
+
+fn part2(input: &Input) -> Crt {
+    draw_crt(input, |_, _| {})
+}
+
+/// The CRT mid-draw, with the sprite shown on the row being drawn.
+struct Screen<'a> {
+    crt: &'a Crt,
+    sprite: i32,
+}
+
+impl Frame for Screen<'_> {
+    fn width(&self) -> usize {
+        40
+    }
+
+    fn height(&self) -> usize {
+        6
+    }
+
+    fn cell(&self, pos: Pos) -> Cell {
+        let lit = self.crt.pixels[pos.0][pos.1] != ' ';
+        let on_sprite = pos.0 == self.crt.cursor.1 && (pos.1 as i32 - self.sprite).abs() <= 1;
+        match (lit, on_sprite) {
+            (true, _) => Cell::new('█', Rgb(90, 220, 110)),
+            (false, true) => Cell::new('░', Rgb(250, 220, 90)),
+            (false, false) => Cell::BLANK,
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, part: u8, sink: &mut dyn Sink) {
+        if part == 2 {
+            draw_crt(input, |crt, sprite| sink.frame(&Screen { crt, sprite }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+    const EXAMPLE_SCREEN: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+    #[test]
+    fn example_part1() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "13140");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        let expected = EXAMPLE_SCREEN.replace('#', "█").replace('.', " ");
+        assert_eq!(part2(&input).to_string(), expected);
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day10>();
+    }
+}
@@ -0,0 +1,260 @@
+use aoc_common::{parse, ParseError, Solution};
+use dyn_clone::DynClone;
+use std::fmt::Display;
+use std::str::FromStr;
+
+trait Operation: DynClone {
+    fn call(&self, x: usize) -> usize;
+}
+
+impl<F> Operation for F
+where
+    F: Fn(usize) -> usize + Clone,
+{
+    fn call(&self, x: usize) -> usize {
+        self(x)
+    }
+}
+
+struct Monkey {
+    items: Vec<usize>,
+    operation: Box<dyn Operation>,
+    divisible: usize,
+    true_monkey: usize,
+    false_monkey: usize,
+    num_inspections: usize,
+}
+
+impl Clone for Monkey {
+    fn clone(&self) -> Self {
+        Monkey {
+            items: self.items.clone(),
+            operation: dyn_clone::clone_box(&*self.operation),
+            ..*self
+        }
+    }
+}
+
+fn field<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    prefix: &str,
+    s: &str,
+) -> Result<&'a str, ParseError> {
+    let line = parse::next(lines, &format!("`{}`", prefix.trim_end()), s)?;
+    parse::prefix(line.trim(), prefix)
+}
+
+/// The monkey an item is thrown to, one of the `monkeys` there are.
+fn target(s: &str, monkeys: usize) -> Result<usize, ParseError> {
+    let monkey = parse::number(s)?;
+    if monkey >= monkeys {
+        return Err(ParseError::new(format!("a monkey below {}", monkeys), s));
+    }
+    Ok(monkey)
+}
+
+impl Monkey {
+    /// Parses one of `monkeys` monkeys.
+    fn parse(s: &str, monkeys: usize) -> Result<Self, ParseError> {
+        let mut line_iter = s.lines();
+        let _header = field(&mut line_iter, "Monkey ", s)?;
+        let items = field(&mut line_iter, "Starting items: ", s)?
+            .split(", ")
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        let operation: Box<dyn Operation> = {
+            let expr = field(&mut line_iter, "Operation: new = old ", s)?;
+            let (op, rhs) = parse::split_once(expr, " ")?;
+            match (op, rhs) {
+                ("*", "old") => Box::new(|x: usize| x * x),
+                ("+", "old") => Box::new(|x: usize| x + x),
+                ("*", rhs) => {
+                    let rhs: usize = parse::number(rhs)?;
+                    Box::new(move |x: usize| x * rhs)
+                }
+                ("+", rhs) => {
+                    let rhs: usize = parse::number(rhs)?;
+                    Box::new(move |x: usize| x + rhs)
+                }
+                (op, _) => return Err(ParseError::new("`*` or `+`", op)),
+            }
+        };
+        let divisible = parse::number(field(&mut line_iter, "Test: divisible by ", s)?)?;
+        let true_monkey = target(
+            field(&mut line_iter, "If true: throw to monkey ", s)?,
+            monkeys,
+        )?;
+        let false_monkey = target(
+            field(&mut line_iter, "If false: throw to monkey ", s)?,
+            monkeys,
+        )?;
+
+        Ok(Self {
+            items,
+            operation,
+            divisible,
+            true_monkey,
+            false_monkey,
+            num_inspections: 0,
+        })
+    }
+
+    fn work(
+        &mut self,
+        manage: impl Fn(usize) -> usize,
+    ) -> ((usize, Vec<usize>), (usize, Vec<usize>)) {
+        self.num_inspections += self.items.len();
+        let mut true_items = Vec::new();
+        let mut false_items = Vec::new();
+        for item in &self.items {
+            let worry = manage(self.operation.call(*item));
+            if worry.is_multiple_of(self.divisible) {
+                true_items.push(worry);
+            } else {
+                false_items.push(worry);
+            }
+        }
+        self.items.clear();
+        (
+            (self.true_monkey, true_items),
+            (self.false_monkey, false_items),
+        )
+    }
+
+    fn give(&mut self, items: &[usize]) {
+        self.items.extend(items);
+    }
+
+    fn business(&self) -> usize {
+        self.num_inspections
+    }
+}
+
+pub struct Input {
+    monkeys: Vec<Monkey>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let blocks: Vec<&str> = s.split("\n\n").collect();
+        let monkeys = blocks
+            .iter()
+            .map(|block| Monkey::parse(block, blocks.len()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { monkeys })
+    }
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, manage: impl Fn(usize) -> usize) -> usize {
+    let mut monkeys = monkeys.to_vec();
+    for _round in 0..rounds {
+        for i in 0..monkeys.len() {
+            let ((monkey0, items0), (monkey1, items1)) = monkeys[i].work(&manage);
+
+            monkeys[monkey0].give(items0.as_slice());
+            monkeys[monkey1].give(items1.as_slice());
+        }
+    }
+
+    let mut monkeys: Vec<_> = monkeys
+        .into_iter()
+        .map(|monkey| monkey.business())
+        .collect();
+    monkeys.sort_by(|a, b| b.cmp(a));
+    monkeys[0] * monkeys[1]
+}
+
+fn part1(input: &Input) -> impl Display {
+    monkey_business(&input.monkeys, 20, |x| x / 3)
+}
+
+fn part2(input: &Input) -> impl Display {
+    let gcd = input
+        .monkeys
+        .iter()
+        .map(|monkey| monkey.divisible)
+        .product::<usize>();
+    monkey_business(&input.monkeys, 10_000, |x| x % gcd)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn example_part1() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "10605");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "2713310158");
+    }
+
+    #[test]
+    fn unknown_monkey() {
+        let s = EXAMPLE.replace("throw to monkey 3", "throw to monkey 4");
+        let err = Day11::parse(&s).err().unwrap().locate(&s);
+        assert_eq!(err.expected(), "a monkey below 4");
+        assert_eq!((err.line(), err.column()), (6, 31));
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day11>();
+    }
+}
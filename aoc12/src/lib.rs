@@ -0,0 +1,193 @@
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::visualize::{Cell, Frame, Rgb, Sink};
+use aoc_common::{search, ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Searches for the shortest climb from `start` to `goal`, calling
+/// `on_explore` with every square as the search expands it.
+fn climb(
+    map: &Grid<u8>,
+    start: Pos,
+    goal: Pos,
+    mut on_explore: impl FnMut(Pos),
+) -> Option<search::Path<Pos, usize>> {
+    // Every step moves one square and climbs at most one elevation.
+    let heuristic = |&pos: &Pos| {
+        let distance = pos.0.abs_diff(goal.0) + pos.1.abs_diff(goal.1);
+        distance.max(map[goal].saturating_sub(map[pos]) as usize)
+    };
+    let neighbours = |&pos: &Pos| {
+        on_explore(pos);
+        map.neighbours4(pos)
+            .filter(move |&next| map[next].saturating_sub(map[pos]) <= 1)
+            .map(|next| (next, 1))
+    };
+    search::astar(start, neighbours, heuristic, |&pos| pos == goal)
+}
+
+fn shortest_path(map: &Grid<u8>, start: Pos, goal: Pos) -> Option<usize> {
+    climb(map, start, goal, |_| {}).map(|path| path.cost)
+}
+
+/// The hill as the search sees it: explored squares coloured by elevation,
+/// and the path once found.
+struct Hill<'a> {
+    map: &'a Grid<u8>,
+    explored: &'a Grid<bool>,
+    path: &'a [Pos],
+    focus: Pos,
+}
+
+impl Frame for Hill<'_> {
+    fn width(&self) -> usize {
+        self.map.width()
+    }
+
+    fn height(&self) -> usize {
+        self.map.height()
+    }
+
+    fn cell(&self, pos: Pos) -> Cell {
+        let glyph = (b'a' + self.map[pos]) as char;
+        if self.path.contains(&pos) {
+            Cell::new(glyph.to_ascii_uppercase(), Rgb(255, 90, 60))
+        } else if self.explored[pos] {
+            let t = self.map[pos] as f32 / 25.0;
+            Cell::new(glyph, Rgb(60, 140, 220).lerp(Rgb(240, 240, 240), t))
+        } else {
+            Cell::new(glyph, Rgb(70, 70, 70))
+        }
+    }
+
+    fn focus(&self) -> Option<Pos> {
+        Some(self.focus)
+    }
+}
+
+pub struct Input {
+    map: Grid<u8>,
+    start: Pos,
+    goal: Pos,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let squares = Grid::parse(s, "an elevation `a`-`z`, `S` or `E`", |c| {
+            matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+        })?;
+        let start = squares
+            .find(&'S')
+            .ok_or_else(|| ParseError::end("a start square `S`", s))?;
+        let goal = squares
+            .find(&'E')
+            .ok_or_else(|| ParseError::end("a goal square `E`", s))?;
+        let map = squares.map(|&c| match c {
+            'S' => 0,
+            'E' => b'z' - b'a',
+            c => c as u8 - b'a',
+        });
+
+        Ok(Self { map, start, goal })
+    }
+}
+
+fn part1(input: &Input) -> impl Display {
+    shortest_path(&input.map, input.start, input.goal).unwrap()
+}
+
+fn part2(input: &Input) -> impl Display {
+    input
+        .map
+        .indexed_iter()
+        .filter(|(_, &val)| val == 0)
+        .filter_map(|(ind, _)| shortest_path(&input.map, ind, input.goal))
+        .min()
+        .unwrap()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, part: u8, sink: &mut dyn Sink) {
+        let map = &input.map;
+        let start = if part == 1 {
+            input.start
+        } else {
+            let starts = map.indexed_iter().filter(|(_, &val)| val == 0);
+            let Some((start, _)) = starts
+                .filter_map(|(ind, _)| Some((ind, shortest_path(map, ind, input.goal)?)))
+                .min_by_key(|&(_, steps)| steps)
+            else {
+                return;
+            };
+            start
+        };
+
+        let mut explored = Grid::from_elem(map.height(), map.width(), false);
+        let path = climb(map, start, input.goal, |pos| {
+            explored[pos] = true;
+            sink.frame(&Hill {
+                map,
+                explored: &explored,
+                path: &[],
+                focus: pos,
+            });
+        });
+        if let Some(path) = path {
+            sink.frame(&Hill {
+                map,
+                explored: &explored,
+                path: &path.nodes,
+                focus: input.goal,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn example_part1() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "31");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "29");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day12>();
+    }
+}
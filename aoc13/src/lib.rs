@@ -0,0 +1,202 @@
+use aoc_common::{parse, ParseError, Solution};
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub struct Input {
+    compare: Vec<(Node, Node)>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let compare = s
+            .split("\n\n")
+            .map(|line| {
+                let (lhs, rhs) = parse::split_once(line, "\n")?;
+                Ok((lhs.parse()?, rhs.parse()?))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { compare })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Node {
+    Array(Vec<Node>),
+    Number(usize),
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: serde_json::Value = serde_json::from_str(s).map_err(|err| {
+            let i = (err.column().max(1) - 1).min(s.len());
+            let found = s[i..]
+                .chars()
+                .next()
+                .map_or("", |c| &s[i..i + c.len_utf8()]);
+            ParseError::new("a packet", found)
+        })?;
+        Node::try_from(value).map_err(|_| ParseError::new("a list of integers", s))
+    }
+}
+
+impl TryFrom<serde_json::Value> for Node {
+    type Error = serde_json::Value;
+
+    fn try_from(v: serde_json::Value) -> Result<Self, Self::Error> {
+        match v {
+            serde_json::Value::Array(arr) => {
+                let nodes = arr
+                    .into_iter()
+                    .map(Node::try_from)
+                    .collect::<Result<_, _>>()?;
+                Ok(Node::Array(nodes))
+            }
+            serde_json::Value::Number(n) => match n.as_u64() {
+                Some(n) => Ok(Node::Number(n as usize)),
+                None => Err(serde_json::Value::Number(n)),
+            },
+            v => Err(v),
+        }
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Node::Number(x), Node::Number(y)) => x.cmp(y),
+            (Node::Number(_), Node::Array(_)) => {
+                let new_self = Node::Array(vec![self.clone()]);
+                new_self.cmp(other)
+            }
+            (Node::Array(_), Node::Number(_)) => {
+                let new_other = Node::Array(vec![other.clone()]);
+                self.cmp(&new_other)
+            }
+            (Node::Array(x), Node::Array(y)) => {
+                for (xi, yi) in x.iter().zip(y.iter()) {
+                    match xi.cmp(yi) {
+                        Ordering::Equal => {}
+                        Ordering::Less => return Ordering::Less,
+                        Ordering::Greater => return Ordering::Greater,
+                    }
+                }
+                x.len().cmp(&y.len())
+            }
+        }
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn part1(input: &Input) -> impl Display {
+    input
+        .compare
+        .iter()
+        .enumerate()
+        .filter_map(|(ind, (lhs, rhs))| {
+            if lhs.cmp(rhs).is_lt() {
+                Some(ind + 1)
+            } else {
+                None
+            }
+        })
+        .sum::<usize>()
+}
+
+fn part2(input: &Input) -> impl Display {
+    let mut packets: Vec<Node> = input
+        .compare
+        .iter()
+        .cloned()
+        .flat_map(|(lhs, rhs)| std::iter::once(lhs).chain(std::iter::once(rhs)))
+        .collect();
+
+    let dividers: [Node; 2] = ["[[2]]".parse().unwrap(), "[[6]]".parse().unwrap()];
+    packets.extend_from_slice(&dividers);
+    packets.sort_unstable();
+
+    dividers
+        .iter()
+        .map(|divider| {
+            let pos = packets.iter().position(|node| node == divider).unwrap();
+            pos + 1
+        })
+        .product::<usize>()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    #[test]
+    fn example_part1() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "13");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "140");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day13>();
+    }
+}
@@ -0,0 +1,275 @@
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::visualize::{self, Cell, Frame, Rgb, Sink};
+use aoc_common::{parse, ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug)]
+struct Coord(usize, usize);
+
+impl FromStr for Coord {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = parse::split_once(s, ",")?;
+        Ok(Coord(parse::number(x)?, parse::number(y)?))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Tile {
+    fn as_char(self) -> char {
+        match self {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        }
+    }
+}
+
+/// Drawn with the same glyphs as the puzzle, which `--palette` picks
+/// colours by.
+impl visualize::Tile for Tile {
+    fn cell(&self) -> Cell {
+        match self {
+            Tile::Air => Cell::BLANK,
+            Tile::Rock => Cell::new('#', Rgb::GREY),
+            Tile::Sand => Cell::new('o', Rgb(222, 184, 96)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Map {
+    tiles: Grid<Tile>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords: Vec<Vec<Coord>> = s
+            .lines()
+            .map(|line| line.split(" -> ").map(str::parse).collect())
+            .collect::<Result<_, _>>()?;
+
+        let max_x = coords
+            .iter()
+            .flat_map(|line| line.iter())
+            .map(|coord| coord.0)
+            .max_by(|a, b| a.cmp(b))
+            .ok_or_else(|| ParseError::end("a rock formation", s))?;
+        let max_y = coords
+            .iter()
+            .flat_map(|line| line.iter())
+            .map(|coord| coord.1)
+            .max_by(|a, b| a.cmp(b))
+            .ok_or_else(|| ParseError::end("a rock formation", s))?;
+
+        let max_x = 2 * max_x;
+        let max_y = max_y + 2;
+
+        let mut tiles = Grid::from_elem(max_y + 1, max_x + 1, Tile::Air);
+        for formation in coords.into_iter() {
+            for line in formation.windows(2) {
+                let start = line[0];
+                let end = line[1];
+                let (x0, x1) = (start.0.min(end.0), start.0.max(end.0));
+                let (y0, y1) = (start.1.min(end.1), start.1.max(end.1));
+                for y in y0..=y1 {
+                    tiles.row_mut(y)[x0..=x1].fill(Tile::Rock);
+                }
+            }
+        }
+        tiles.row_mut(max_y).fill(Tile::Rock);
+
+        Ok(Self { tiles })
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles.render(|tile| tile.as_char()))
+    }
+}
+
+impl Map {
+    /// Drops a grain of sand from column `x`, returning where it came to rest.
+    fn drop_sand(&mut self, x: usize, stop_early: bool) -> Option<Coord> {
+        let mut sand = Coord(x, 0);
+        if let Tile::Sand = self.tiles[(sand.1, sand.0)] {
+            return None;
+        }
+
+        'outer: loop {
+            let check = &[
+                (sand.1 + 1, sand.0),
+                (sand.1 + 1, sand.0 - 1),
+                (sand.1 + 1, sand.0 + 1),
+            ];
+            for &ind in check {
+                if let Tile::Air = self.tiles.get(ind)? {
+                    sand.0 = ind.1;
+                    sand.1 = ind.0;
+                    continue 'outer;
+                }
+            }
+
+            break;
+        }
+
+        if stop_early {
+            let bedrock = self.tiles.height() - 2;
+            if sand.1 == bedrock {
+                return None;
+            }
+        }
+
+        self.tiles[(sand.1, sand.0)] = Tile::Sand;
+        Some(sand)
+    }
+}
+
+/// The part of the map sand can reach: a triangle widening by a column on
+/// each side for every row below the source.
+struct View<'a> {
+    map: &'a Map,
+    left: usize,
+    width: usize,
+    sand: Coord,
+}
+
+impl<'a> View<'a> {
+    fn new(map: &'a Map, source: usize, sand: Coord) -> Self {
+        let tiles = &map.tiles;
+        let left = source.saturating_sub(tiles.height());
+        let right = (source + tiles.height()).min(tiles.width() - 1);
+        Self {
+            map,
+            left,
+            width: right - left + 1,
+            sand,
+        }
+    }
+}
+
+impl Frame for View<'_> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.map.tiles.height()
+    }
+
+    fn cell(&self, pos: Pos) -> Cell {
+        visualize::Tile::cell(&self.map.tiles[(pos.0, self.left + pos.1)])
+    }
+
+    fn focus(&self) -> Option<Pos> {
+        Some((self.sand.1, self.sand.0 - self.left))
+    }
+}
+
+const SOURCE: usize = 500;
+
+/// Pours sand until it stops coming to rest, calling `on_sand` for every
+/// grain that does, and returns how many did.
+fn fill(map: &Map, stop_early: bool, mut on_sand: impl FnMut(&Map, Coord)) -> usize {
+    let mut map = map.clone();
+    let mut grains = 0;
+    while let Some(sand) = map.drop_sand(SOURCE, stop_early) {
+        grains += 1;
+        on_sand(&map, sand);
+    }
+    grains
+}
+
+pub struct Input {
+    map: Map,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { map: s.parse()? })
+    }
+}
+
+fn part1(input: &Input) -> impl Display {
+    fill(&input.map, true, |_, _| {})
+}
+
+fn part2(input: &Input) -> impl Display {
+    fill(&input.map, false, |_, _| {})
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, part: u8, sink: &mut dyn Sink) {
+        fill(&input.map, part == 1, |map, sand| {
+            sink.frame(&View::new(map, SOURCE, sand))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn example_part1() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "24");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "93");
+    }
+
+    #[test]
+    fn palette_recolours_rock_and_sand() {
+        use aoc_common::visualize::{Palette, Tile as _};
+
+        let palette: Palette = "#=ff0000,o=00ff00".parse().unwrap();
+        let rock = palette.paint(Tile::Rock.cell());
+        assert_eq!(rock.color, Rgb(255, 0, 0));
+        let sand = palette.paint(Tile::Sand.cell());
+        assert_eq!(sand.color, Rgb(0, 255, 0));
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day14>();
+    }
+}
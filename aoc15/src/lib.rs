@@ -0,0 +1,188 @@
+use aoc_common::{parse, ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+type Coord = (i32, i32);
+
+struct Sensor {
+    pos: Coord,
+    beacon: Coord,
+}
+
+impl Sensor {
+    fn manhatten_distance(&self) -> i32 {
+        (self.pos.0 - self.beacon.0).abs() + (self.pos.1 - self.beacon.1).abs()
+    }
+
+    fn covers_y(&self, y: i32) -> Option<Vec<i32>> {
+        let distance = self.manhatten_distance();
+        let y_diff = (y - self.pos.1).abs();
+        if y_diff > distance {
+            return None;
+        }
+
+        let x_start = self.pos.0 - (distance - y_diff);
+        let x_stop = self.pos.0 + (distance - y_diff);
+        let positions: Vec<i32> = (x_start..=x_stop).collect();
+        Some(positions)
+    }
+
+    fn covers(&self, coord: &Coord) -> bool {
+        let x_diff = (self.pos.0 - coord.0).abs();
+        let y_diff = (self.pos.1 - coord.1).abs();
+        (x_diff + y_diff) <= self.manhatten_distance()
+    }
+
+    fn surrounding(&self) -> Vec<Coord> {
+        let distance = self.manhatten_distance() + 1;
+        let mut positions = Vec::with_capacity((distance * 4) as usize);
+        for d in 0..=distance {
+            let x = d;
+            let y = distance - d;
+            if y == 0 {
+                positions.push((self.pos.0 + x, self.pos.1));
+                positions.push((self.pos.0 - x, self.pos.1));
+            } else {
+                positions.push((self.pos.0 + x, self.pos.1 + y));
+                positions.push((self.pos.0 + x, self.pos.1 - y));
+                positions.push((self.pos.0 - x, self.pos.1 + y));
+                positions.push((self.pos.0 - x, self.pos.1 - y));
+            }
+        }
+        positions
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = parse::prefix(s, "Sensor at ")?;
+        let (pos, beacon) = parse::split_once(s, ": closest beacon is at ")?;
+        let (pos_x, pos_y) = parse::split_once(pos, ", ")?;
+        let (beacon_x, beacon_y) = parse::split_once(beacon, ", ")?;
+        let pos_x = parse::prefix(pos_x, "x=")?;
+        let pos_y = parse::prefix(pos_y, "y=")?;
+        let beacon_x = parse::prefix(beacon_x, "x=")?;
+        let beacon_y = parse::prefix(beacon_y, "y=")?;
+        let pos = (parse::number(pos_x)?, parse::number(pos_y)?);
+        let beacon = (parse::number(beacon_x)?, parse::number(beacon_y)?);
+        Ok(Self { pos, beacon })
+    }
+}
+
+pub struct Input {
+    sensors: Vec<Sensor>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sensors = s.lines().map(str::parse).collect::<Result<_, _>>()?;
+        Ok(Self { sensors })
+    }
+}
+
+fn no_beacon_positions(input: &Input, y: i32) -> usize {
+    let beacons: HashSet<i32> = input
+        .sensors
+        .iter()
+        .filter_map(|sensor| {
+            if sensor.beacon.1 == y {
+                Some(sensor.beacon.0)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let mut positions: HashSet<i32> = input
+        .sensors
+        .iter()
+        .filter_map(|sensor| sensor.covers_y(y))
+        .flat_map(|positions| positions.into_iter())
+        .collect();
+    positions.retain(|x| !beacons.contains(x));
+
+    positions.len()
+}
+
+fn tuning_frequency(input: &Input, max: i32) -> usize {
+    let (x, y) = input
+        .sensors
+        .iter()
+        .flat_map(|sensor| sensor.surrounding().into_iter())
+        .filter(|&(x, y)| (0..=max).contains(&x) && (0..=max).contains(&y))
+        .find(|coord| !input.sensors.iter().any(|sensor| sensor.covers(coord)))
+        .unwrap();
+
+    x as usize * 4000000 + y as usize
+}
+
+fn part1(input: &Input) -> impl Display {
+    no_beacon_positions(input, 2000000)
+}
+
+fn part2(input: &Input) -> impl Display {
+    tuning_frequency(input, 4000000)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn example_part1() {
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(no_beacon_positions(&input, 10), 26);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(tuning_frequency(&input, 20), 56000011);
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day15>();
+    }
+}
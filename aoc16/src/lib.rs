@@ -0,0 +1,278 @@
+use aoc_common::{parse, search, ParseError, Solution};
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::str::FromStr;
+
+type Node = [u8; 2];
+
+/// Where you start, with the elephant.
+const START: Node = *b"AA";
+
+#[derive(Debug)]
+pub struct Input {
+    valves: HashMap<Node, usize>,
+    graph: HashMap<Node, Vec<Node>>,
+}
+
+fn parse_node(s: &str) -> Result<Node, ParseError> {
+    s.as_bytes()
+        .try_into()
+        .map_err(|_| ParseError::new("a two-letter valve name", s))
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut valves = HashMap::new();
+        let mut graph = HashMap::new();
+        let mut tunnels = Vec::new();
+        for line in s.lines() {
+            let line = parse::prefix(line, "Valve ")?;
+            let (node, line) = parse::split_once(line, " has flow rate=")?;
+            let (flow_rate, line) = parse::split_once(line, "; ")?;
+            let node = parse_node(node)?;
+            let flow_rate = parse::number(flow_rate)?;
+            let lead_to = parse::prefix(line, "tunnels lead to valves ")
+                .or_else(|_| parse::prefix(line, "tunnel leads to valve "))?;
+            tunnels.extend(lead_to.split(", "));
+            let lead_to: Vec<Node> = lead_to
+                .split(", ")
+                .map(parse_node)
+                .collect::<Result<_, _>>()?;
+            if flow_rate != 0 {
+                valves.insert(node, flow_rate);
+            }
+            graph.insert(node, lead_to);
+        }
+        if !graph.contains_key(&START) {
+            return Err(ParseError::end("a valve `AA` to start from", s));
+        }
+        // Every tunnel has to lead to a valve of its own.
+        if let Some(tunnel) = tunnels
+            .into_iter()
+            .find(|tunnel| !graph.contains_key(tunnel.as_bytes()))
+        {
+            return Err(ParseError::new("the name of a valve", tunnel));
+        }
+        Ok(Self { valves, graph })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct State {
+    to: Node,
+    cost: usize,
+    time_left: usize,
+    flow: usize,
+    pressure: usize,
+    to_open: Vec<Node>,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // max heap
+        other.time_left.cmp(&self.time_left)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Minutes to walk from one valve to the other, and open it.
+fn open_cost(graph: &HashMap<Node, Vec<Node>>, vertex: &(Node, Node)) -> usize {
+    let path = search::bfs(
+        vertex.0,
+        |node| graph[node].iter().copied(),
+        |&node| node == vertex.1,
+    );
+    path.unwrap().cost + 1
+}
+
+fn max_pressure(
+    path_cost: &mut HashMap<(Node, Node), usize>,
+    valves: &HashMap<Node, usize>,
+    graph: &HashMap<Node, Vec<Node>>,
+    max_time: usize,
+) -> usize {
+    let to_open: Vec<Node> = valves.keys().copied().collect();
+
+    let mut heap: BinaryHeap<State> = BinaryHeap::new();
+    for valve in &to_open {
+        let vertex = (START, *valve);
+        let to_open = to_open
+            .iter()
+            .copied()
+            .filter(|node| node != valve)
+            .collect();
+        heap.push(State {
+            to: *valve,
+            cost: open_cost(graph, &vertex),
+            time_left: max_time,
+            flow: 0,
+            pressure: 0,
+            to_open,
+        });
+    }
+
+    let mut max_pressure = 0;
+
+    while let Some(State {
+        to,
+        cost,
+        mut time_left,
+        mut flow,
+        mut pressure,
+        to_open,
+    }) = heap.pop()
+    {
+        let cost = cost.min(time_left);
+        time_left -= cost;
+        pressure += flow * cost;
+
+        if time_left == 0 {
+            max_pressure = max_pressure.max(pressure);
+            continue;
+        }
+
+        flow += valves[&to];
+
+        // With every valve open, the flow keeps on for the minutes left;
+        // stopping the count here would drop them.
+        if to_open.is_empty() {
+            max_pressure = max_pressure.max(pressure + flow * time_left);
+            continue;
+        }
+
+        for next in to_open.iter() {
+            let vertex = (to, *next);
+            let cost = *path_cost
+                .entry(vertex)
+                .or_insert_with(|| open_cost(graph, &vertex));
+
+            let to_open = to_open
+                .iter()
+                .copied()
+                .filter(|node| node != next)
+                .collect();
+            heap.push(State {
+                to: *next,
+                cost,
+                time_left,
+                flow,
+                pressure,
+                to_open,
+            });
+        }
+    }
+
+    max_pressure
+}
+
+fn part1(input: &Input) -> impl Display {
+    const MAX_TIME: usize = 30;
+    let mut path_cost: HashMap<(Node, Node), usize> = HashMap::new();
+    max_pressure(&mut path_cost, &input.valves, &input.graph, MAX_TIME)
+}
+
+fn part2(input: &Input) -> impl Display {
+    const MAX_TIME: usize = 26;
+    let mut path_cost: HashMap<(Node, Node), usize> = HashMap::new();
+    (0..=input.valves.len() / 2)
+        .flat_map(|n| input.valves.clone().into_iter().combinations(n))
+        .map(|valves| {
+            let valves0: HashMap<Node, usize> = valves.into_iter().collect();
+            let valves1: HashMap<Node, usize> = input
+                .valves
+                .clone()
+                .into_iter()
+                .filter(|(key, _)| !valves0.contains_key(key))
+                .collect();
+            max_pressure(&mut path_cost, &valves0, &input.graph, MAX_TIME)
+                + max_pressure(&mut path_cost, &valves1, &input.graph, MAX_TIME)
+        })
+        .max()
+        .unwrap()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn example_part1() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "1651");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "1707");
+    }
+
+    #[test]
+    fn every_valve_open_early() {
+        let s = "\
+Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=10; tunnel leads to valve AA";
+        let input = Day16::parse(s).unwrap();
+        // A minute to walk to BB and one to open it leave 28 minutes of flow.
+        assert_eq!(part1(&input).to_string(), "280");
+    }
+
+    #[test]
+    fn unknown_valves() {
+        let s = EXAMPLE.replace("valves CC, AA", "valves CC, ZZ");
+        let err = Day16::parse(&s).unwrap_err().locate(&s);
+        assert_eq!(err.found(), "ZZ");
+        assert_eq!((err.line(), err.column()), (2, 55));
+        let s = EXAMPLE.replace("Valve AA", "Valve AB");
+        assert_eq!(
+            Day16::parse(&s).unwrap_err().expected(),
+            "a valve `AA` to start from"
+        );
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day16>();
+    }
+}
@@ -0,0 +1,205 @@
+use aoc_common::{ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Jet {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    jets: Vec<Jet>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let jets = s
+            .char_indices()
+            .map(|(i, c)| match c {
+                '<' => Ok(Jet::Left),
+                '>' => Ok(Jet::Right),
+                _ => Err(ParseError::new("a jet `<` or `>`", &s[i..i + c.len_utf8()])),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if jets.is_empty() {
+            return Err(ParseError::end("a jet pattern", s));
+        }
+
+        Ok(Self { jets })
+    }
+}
+
+/// The five rocks, bottom row first, as they appear: with their left edge two
+/// units from the left wall. Bit 6 is the leftmost column of the chamber.
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+const WIDTH: usize = 7;
+
+struct Chamber<'a> {
+    jets: &'a [Jet],
+    jet: usize,
+    rocks: usize,
+    rows: Vec<u8>,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [Jet]) -> Self {
+        Self {
+            jets,
+            jet: 0,
+            rocks: 0,
+            rows: Vec::new(),
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn fits(&self, rock: &[u8], y: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .all(|(i, row)| self.rows.get(y + i).is_none_or(|filled| filled & row == 0))
+    }
+
+    fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.rocks % ROCKS.len()].to_vec();
+        self.rocks += 1;
+        let mut y = self.height() + 3;
+        loop {
+            let jet = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            let pushed: Vec<u8> = match jet {
+                Jet::Left if rock.iter().all(|row| row & 1 << (WIDTH - 1) == 0) => {
+                    rock.iter().map(|row| row << 1).collect()
+                }
+                Jet::Right if rock.iter().all(|row| row & 1 == 0) => {
+                    rock.iter().map(|row| row >> 1).collect()
+                }
+                _ => rock.clone(),
+            };
+            if self.fits(&pushed, y) {
+                rock = pushed;
+            }
+
+            if y == 0 || !self.fits(&rock, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (i, row) in rock.into_iter().enumerate() {
+            match self.rows.get_mut(y + i) {
+                Some(filled) => *filled |= row,
+                None => self.rows.push(row),
+            }
+        }
+    }
+
+    /// How far down from the top the first rock is in each column, which is
+    /// all that matters for where the next rocks come to rest.
+    fn profile(&self) -> [u8; WIDTH] {
+        let mut profile = [u8::MAX; WIDTH];
+        for (depth, row) in self.rows.iter().rev().take(u8::MAX as usize).enumerate() {
+            for (x, column) in profile.iter_mut().enumerate() {
+                if *column == u8::MAX && row & 1 << (WIDTH - 1 - x) != 0 {
+                    *column = depth as u8;
+                }
+            }
+            if profile.iter().all(|&column| column != u8::MAX) {
+                break;
+            }
+        }
+        profile
+    }
+}
+
+/// The height of the tower after `rocks` rocks have come to rest.
+///
+/// Once the chamber is back in a state it has been in before — the same
+/// next rock, the same next jet and the same surface — it repeats, so the
+/// height of the remaining rocks follows from the heights seen so far.
+fn tower_height(jets: &[Jet], rocks: usize) -> usize {
+    let mut chamber = Chamber::new(jets);
+    let mut seen: HashMap<(usize, usize, [u8; WIDTH]), usize> = HashMap::new();
+    let mut heights = vec![0];
+    for dropped in 0..rocks {
+        let state = (dropped % ROCKS.len(), chamber.jet, chamber.profile());
+        if let Some(&start) = seen.get(&state) {
+            let period = dropped - start;
+            let growth = heights[dropped] - heights[start];
+            let left = rocks - dropped;
+            let rest = heights[start + left % period] - heights[start];
+            return heights[dropped] + left / period * growth + rest;
+        }
+        seen.insert(state, dropped);
+
+        chamber.drop_rock();
+        heights.push(chamber.height());
+    }
+    chamber.height()
+}
+
+fn part1(input: &Input) -> usize {
+    tower_height(&input.jets, 2022)
+}
+
+fn part2(input: &Input) -> usize {
+    tower_height(&input.jets, 1_000_000_000_000)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+>>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn example_part1() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "3068");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "1514285714288");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day17>();
+    }
+}
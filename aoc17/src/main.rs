@@ -1,3 +1,5 @@
+use aoc17::Day17;
+
 fn main() {
-    println!("Hello, world!");
+    aoc_common::main::<Day17>();
 }
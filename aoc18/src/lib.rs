@@ -0,0 +1,141 @@
+use aoc_common::voxel::{Voxel, VoxelGrid};
+use aoc_common::{parse, ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Space {
+    Air,
+    Lava,
+    Steam,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    /// The droplet, with a layer of air all around it.
+    grid: VoxelGrid<Space>,
+    cubes: Vec<Voxel>,
+}
+
+fn parse_cube(s: &str) -> Result<Voxel, ParseError> {
+    let (x, rest) = parse::split_once(s, ",")?;
+    let (y, z) = parse::split_once(rest, ",")?;
+    Ok((parse::number(x)?, parse::number(y)?, parse::number(z)?))
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cubes = s
+            .lines()
+            .map(|line| {
+                let (x, y, z) = parse_cube(line)?;
+                Ok((x + 1, y + 1, z + 1))
+            })
+            .collect::<Result<Vec<Voxel>, ParseError>>()?;
+        if cubes.is_empty() {
+            return Err(ParseError::end("a cube `x,y,z`", s));
+        }
+
+        let size = cubes.iter().fold((0, 0, 0), |size, &(x, y, z)| {
+            (size.0.max(x + 2), size.1.max(y + 2), size.2.max(z + 2))
+        });
+        let mut grid = VoxelGrid::from_elem(size, Space::Air);
+        for &cube in &cubes {
+            grid[cube] = Space::Lava;
+        }
+
+        Ok(Self { grid, cubes })
+    }
+}
+
+/// The number of cube faces that touch a voxel of `outside`.
+fn surface_area(grid: &VoxelGrid<Space>, cubes: &[Voxel], outside: Space) -> usize {
+    cubes
+        .iter()
+        .map(|&cube| {
+            grid.neighbours6(cube)
+                .filter(|&voxel| grid[voxel] == outside)
+                .count()
+        })
+        .sum()
+}
+
+fn part1(input: &Input) -> usize {
+    surface_area(&input.grid, &input.cubes, Space::Air)
+}
+
+fn part2(input: &Input) -> usize {
+    // Fill the air from a corner, which is outside thanks to the padding;
+    // air pockets inside the droplet are never reached.
+    let mut grid = input.grid.clone();
+    let mut stack = vec![(0, 0, 0)];
+    grid[(0, 0, 0)] = Space::Steam;
+    while let Some(voxel) = stack.pop() {
+        for next in grid.neighbours6(voxel).collect::<Vec<_>>() {
+            if grid[next] == Space::Air {
+                grid[next] = Space::Steam;
+                stack.push(next);
+            }
+        }
+    }
+    surface_area(&grid, &input.cubes, Space::Steam)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    #[test]
+    fn example_part1() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "64");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "58");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day18>();
+    }
+}
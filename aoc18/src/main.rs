@@ -1,3 +1,5 @@
+use aoc18::Day18;
+
 fn main() {
-    println!("Hello, world!");
+    aoc_common::main::<Day18>();
 }
@@ -0,0 +1,293 @@
+use aoc_common::{parse, ParseError, Solution};
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+const RESOURCES: [&str; 4] = ["ore", "clay", "obsidian", "geode"];
+const GEODE: usize = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Blueprint {
+    id: u32,
+    /// What each robot costs, by robot and then by resource. Nothing costs
+    /// geodes, so those are left out.
+    costs: [[u32; 3]; 4],
+}
+
+fn parse_resource(s: &str) -> Result<usize, ParseError> {
+    RESOURCES
+        .iter()
+        .position(|&resource| resource == s)
+        .ok_or_else(|| ParseError::new("a resource", s))
+}
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = parse::prefix(s, "Blueprint ")?;
+        let (id, mut rest) = parse::split_once(s, ":")?;
+        let mut costs = [[0; 3]; 4];
+        for (robot, resource) in RESOURCES.iter().enumerate() {
+            let robot_costs = parse::prefix(
+                rest.trim_start(),
+                &format!("Each {} robot costs ", resource),
+            )?;
+            let (robot_costs, next) = parse::split_once(robot_costs, ".")?;
+            for cost in robot_costs.split(" and ") {
+                let (amount, resource) = parse::split_once(cost, " ")?;
+                match parse_resource(resource)? {
+                    GEODE => return Err(ParseError::new("a resource other than geode", resource)),
+                    resource => costs[robot][resource] = parse::number(amount)?,
+                }
+            }
+            rest = next;
+        }
+        if !rest.trim().is_empty() {
+            return Err(ParseError::new("the end of the blueprint", rest.trim()));
+        }
+
+        Ok(Self {
+            id: parse::number(id)?,
+            costs,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    blueprints: Vec<Blueprint>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let blueprints = s.lines().map(str::parse).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { blueprints })
+    }
+}
+
+/// The most geodes a blueprint can open, and how many states the search
+/// visited to be sure of it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub geodes: u32,
+    pub nodes: usize,
+}
+
+struct Search<'a> {
+    costs: &'a [[u32; 3]; 4],
+    /// Only one robot can be built per minute, so there is no point in
+    /// having more robots of a kind than any robot costs of its resource.
+    caps: [u32; 3],
+    best: u32,
+    nodes: usize,
+}
+
+impl Search<'_> {
+    /// Explores what to build next with `minutes` left, instead of what to
+    /// do each minute: every branch waits, idle, until it can afford its
+    /// robot, so idle minutes are never a branch of their own.
+    fn explore(&mut self, minutes: u32, robots: [u32; 4], stock: [u32; 4]) {
+        self.nodes += 1;
+        self.best = self.best.max(stock[GEODE] + robots[GEODE] * minutes);
+
+        // Even building a geode robot every minute from now on cannot beat
+        // the best so far.
+        let bound =
+            stock[GEODE] + robots[GEODE] * minutes + minutes * minutes.saturating_sub(1) / 2;
+        if bound <= self.best {
+            return;
+        }
+
+        for robot in (0..RESOURCES.len()).rev() {
+            if robot != GEODE {
+                let cap = self.caps[robot];
+                // Enough robots, or enough in stock to spend the cap every
+                // minute that is left.
+                if robots[robot] >= cap || robots[robot] * minutes + stock[robot] >= cap * minutes {
+                    continue;
+                }
+            }
+
+            let Some(wait) = self.wait(robot, robots, stock) else {
+                continue;
+            };
+            // A robot built in the last minute never gets to collect.
+            if wait + 1 >= minutes {
+                continue;
+            }
+
+            let mut next_stock = stock;
+            for (resource, amount) in next_stock.iter_mut().enumerate() {
+                *amount += robots[resource] * (wait + 1);
+            }
+            for (resource, cost) in self.costs[robot].iter().enumerate() {
+                next_stock[resource] -= cost;
+            }
+            let mut next_robots = robots;
+            next_robots[robot] += 1;
+            self.explore(minutes - wait - 1, next_robots, next_stock);
+        }
+    }
+
+    /// How many minutes to collect before `robot` is affordable, if ever.
+    fn wait(&self, robot: usize, robots: [u32; 4], stock: [u32; 4]) -> Option<u32> {
+        self.costs[robot]
+            .iter()
+            .enumerate()
+            .map(|(resource, &cost)| {
+                if stock[resource] >= cost {
+                    Some(0)
+                } else if robots[resource] == 0 {
+                    None
+                } else {
+                    Some((cost - stock[resource]).div_ceil(robots[resource]))
+                }
+            })
+            .try_fold(0, |wait, minutes| Some(wait.max(minutes?)))
+    }
+}
+
+/// The most geodes `blueprint` can open in `minutes`, starting out with a
+/// single ore robot.
+pub fn max_geodes(blueprint: &Blueprint, minutes: u32) -> Outcome {
+    let costs = &blueprint.costs;
+    let mut caps = [0; 3];
+    for (resource, cap) in caps.iter_mut().enumerate() {
+        *cap = costs.iter().map(|cost| cost[resource]).max().unwrap_or(0);
+    }
+
+    let mut search = Search {
+        costs,
+        caps,
+        best: 0,
+        nodes: 0,
+    };
+    search.explore(minutes, [1, 0, 0, 0], [0; 4]);
+    Outcome {
+        geodes: search.best,
+        nodes: search.nodes,
+    }
+}
+
+/// The blueprints a part considers, and for how many minutes.
+fn blueprints(input: &Input, part: u8) -> (&[Blueprint], u32) {
+    match part {
+        1 => (&input.blueprints, 24),
+        _ => (&input.blueprints[..input.blueprints.len().min(3)], 32),
+    }
+}
+
+fn part1(input: &Input) -> u32 {
+    let (blueprints, minutes) = blueprints(input, 1);
+    blueprints
+        .iter()
+        .map(|blueprint| blueprint.id * max_geodes(blueprint, minutes).geodes)
+        .sum()
+}
+
+fn part2(input: &Input) -> u32 {
+    let (blueprints, minutes) = blueprints(input, 2);
+    blueprints
+        .iter()
+        .map(|blueprint| max_geodes(blueprint, minutes).geodes)
+        .product()
+}
+
+/// A table of how many search nodes each blueprint took in `part`, or in
+/// both parts when `part` is `None`.
+pub fn nodes(input: &Input, part: Option<u8>) -> String {
+    let mut table = format!(
+        "{:>4}  {:>9}  {:>7}  {:>6}  {:>10}\n",
+        "part", "blueprint", "minutes", "geodes", "nodes"
+    );
+    for part in [1, 2]
+        .into_iter()
+        .filter(|&p| part.is_none_or(|part| part == p))
+    {
+        let (blueprints, minutes) = blueprints(input, part);
+        let mut total = 0;
+        for blueprint in blueprints {
+            let outcome = max_geodes(blueprint, minutes);
+            total += outcome.nodes;
+            writeln!(
+                table,
+                "{:>4}  {:>9}  {:>7}  {:>6}  {:>10}",
+                part, blueprint.id, minutes, outcome.geodes, outcome.nodes
+            )
+            .unwrap();
+        }
+        writeln!(
+            table,
+            "{:>4}  {:>9}  {:>7}  {:>6}  {:>10}",
+            part, "total", "", "", total
+        )
+        .unwrap();
+    }
+    table
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    #[test]
+    fn parse_blueprint() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            input.blueprints[1],
+            Blueprint {
+                id: 2,
+                costs: [[2, 0, 0], [3, 0, 0], [3, 8, 0], [3, 0, 12]],
+            }
+        );
+    }
+
+    #[test]
+    fn example_part1() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "33");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        let geodes: Vec<u32> = input
+            .blueprints
+            .iter()
+            .map(|blueprint| max_geodes(blueprint, 32).geodes)
+            .collect();
+        assert_eq!(geodes, [56, 62]);
+        assert_eq!(part2(&input).to_string(), "3472");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day19>();
+    }
+}
@@ -1,3 +1,17 @@
+use aoc19::Day19;
+
+#[derive(clap::Args)]
+struct Options {
+    /// Print how many search nodes each blueprint took instead of the answers
+    #[arg(long)]
+    nodes: bool,
+}
+
 fn main() {
-    println!("Hello, world!");
+    aoc_common::main_with::<Day19, Options>(|options, args, source| {
+        options.nodes.then(|| {
+            let input = aoc_common::parse_input::<Day19>(&source.read()?)?;
+            Ok(aoc19::nodes(&input, args.part))
+        })
+    });
 }
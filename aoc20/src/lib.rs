@@ -0,0 +1,120 @@
+use aoc_common::{parse, ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+
+#[derive(Debug)]
+pub struct Input {
+    numbers: Vec<i64>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = s
+            .lines()
+            .map(parse::number)
+            .collect::<Result<Vec<_>, _>>()?;
+        if !numbers.contains(&0) {
+            return Err(ParseError::end("a `0` in the file", s));
+        }
+
+        Ok(Self { numbers })
+    }
+}
+
+/// Mixes `numbers` `rounds` times and returns them in their final order.
+///
+/// Values repeat, so rather than moving values around, this moves the
+/// indices of the original numbers: `order` lists which original number is
+/// at each position of the circle.
+fn mix(numbers: &[i64], rounds: usize) -> Vec<i64> {
+    let mut order: Vec<usize> = (0..numbers.len()).collect();
+    // With the moved number taken out, there are `len - 1` slots to cycle
+    // through.
+    let slots = numbers.len() as i64 - 1;
+    for _ in 0..rounds {
+        for (i, &number) in numbers.iter().enumerate() {
+            if slots == 0 {
+                break;
+            }
+            let from = order.iter().position(|&j| j == i).unwrap();
+            order.remove(from);
+            let to = (from as i64 + number).rem_euclid(slots) as usize;
+            order.insert(to, i);
+        }
+    }
+    order.into_iter().map(|i| numbers[i]).collect()
+}
+
+/// The sum of the numbers 1000, 2000 and 3000 places after the `0`.
+fn grove_coordinates(mixed: &[i64]) -> i64 {
+    let zero = mixed.iter().position(|&n| n == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| mixed[(zero + offset) % mixed.len()])
+        .sum()
+}
+
+fn part1(input: &Input) -> i64 {
+    grove_coordinates(&mix(&input.numbers, 1))
+}
+
+fn part2(input: &Input) -> i64 {
+    let numbers: Vec<i64> = input.numbers.iter().map(|n| n * DECRYPTION_KEY).collect();
+    grove_coordinates(&mix(&numbers, 10))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1
+2
+-3
+3
+-2
+0
+4";
+
+    #[test]
+    fn example_part1() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(mix(&input.numbers, 1), [-2, 1, 2, -3, 4, 0, 3]);
+        assert_eq!(part1(&input).to_string(), "3");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "1623178306");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day20>();
+    }
+}
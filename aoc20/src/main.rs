@@ -1,3 +1,5 @@
+use aoc20::Day20;
+
 fn main() {
-    println!("Hello, world!");
+    aoc_common::main::<Day20>();
 }
@@ -0,0 +1,285 @@
+use aoc_common::{parse, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Op::Add),
+            "-" => Ok(Op::Sub),
+            "*" => Ok(Op::Mul),
+            "/" => Ok(Op::Div),
+            _ => Err(ParseError::new("an operation `+`, `-`, `*` or `/`", s)),
+        }
+    }
+}
+
+impl Op {
+    fn apply(self, a: i64, b: i64) -> i64 {
+        match self {
+            Op::Add => a + b,
+            Op::Sub => a - b,
+            Op::Mul => a * b,
+            Op::Div => a / b,
+        }
+    }
+}
+
+/// What a monkey yells, with operands given as indices of other monkeys.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Job<M> {
+    Number(i64),
+    Op(Op, M, M),
+}
+
+#[derive(Debug)]
+pub struct Input {
+    jobs: Vec<Job<usize>>,
+    /// Every monkey, after the monkeys it waits for.
+    order: Vec<usize>,
+    root: usize,
+    humn: usize,
+}
+
+/// Lists the monkeys `monkey` waits for, then `monkey` itself, into
+/// `order`, unless they are already there.
+fn visit(
+    jobs: &[Job<&str>],
+    indices: &HashMap<&str, usize>,
+    monkey: usize,
+    done: &mut [Option<bool>],
+    order: &mut Vec<usize>,
+) -> Result<(), ParseError> {
+    match done[monkey] {
+        Some(true) => return Ok(()),
+        Some(false) => unreachable!("cycles are reported by the caller"),
+        None => {}
+    }
+    done[monkey] = Some(false);
+    if let Job::Op(_, a, b) = jobs[monkey] {
+        for operand in [a, b] {
+            let &next = indices
+                .get(operand)
+                .ok_or_else(|| ParseError::new("the name of a monkey", operand))?;
+            if done[next] == Some(false) {
+                return Err(ParseError::new(
+                    "a monkey that does not wait for itself",
+                    operand,
+                ));
+            }
+            visit(jobs, indices, next, done, order)?;
+        }
+    }
+    done[monkey] = Some(true);
+    order.push(monkey);
+    Ok(())
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut indices = HashMap::new();
+        let mut jobs = Vec::new();
+        for line in s.lines() {
+            let (name, job) = parse::split_once(line, ": ")?;
+            if indices.insert(name, jobs.len()).is_some() {
+                return Err(ParseError::new("a monkey not seen before", name));
+            }
+            let job = match job.split(' ').collect::<Vec<_>>()[..] {
+                [a, op, b] => Job::Op(op.parse()?, a, b),
+                _ => Job::Number(parse::number(job)?),
+            };
+            jobs.push(job);
+        }
+        let monkey = |name| {
+            indices
+                .get(name)
+                .copied()
+                .ok_or_else(|| ParseError::end(format!("a monkey named `{}`", name), s))
+        };
+        let root = monkey("root")?;
+        let humn = monkey("humn")?;
+
+        let mut done = vec![None; jobs.len()];
+        let mut order = Vec::with_capacity(jobs.len());
+        for monkey in 0..jobs.len() {
+            visit(&jobs, &indices, monkey, &mut done, &mut order)?;
+        }
+        let jobs = jobs
+            .into_iter()
+            .map(|job| match job {
+                Job::Number(n) => Job::Number(n),
+                Job::Op(op, a, b) => Job::Op(op, indices[a], indices[b]),
+            })
+            .collect();
+
+        Ok(Self {
+            jobs,
+            order,
+            root,
+            humn,
+        })
+    }
+}
+
+/// What every monkey yells.
+fn evaluate(input: &Input) -> Vec<i64> {
+    let mut values = vec![0; input.jobs.len()];
+    for &monkey in &input.order {
+        values[monkey] = match input.jobs[monkey] {
+            Job::Number(n) => n,
+            Job::Op(op, a, b) => op.apply(values[a], values[b]),
+        };
+    }
+    values
+}
+
+/// `a / b`, if that leaves no remainder.
+fn exact_div(a: i64, b: i64) -> Option<i64> {
+    (b != 0 && a % b == 0).then(|| a / b)
+}
+
+/// The number `humn` has to yell for the two operands of `root` to be
+/// equal.
+///
+/// Working down from `root` along the only path to `humn`, every operation
+/// has one operand that does not depend on `humn` and so is known, which
+/// is enough to undo the operation and learn what the other operand has to
+/// be. There is no answer if both operands depend on `humn`, or if undoing
+/// an operation takes a fraction.
+fn solve(input: &Input) -> Option<i64> {
+    let values = evaluate(input);
+    let mut unknown = vec![false; input.jobs.len()];
+    unknown[input.humn] = true;
+    for &monkey in &input.order {
+        if let Job::Op(_, a, b) = input.jobs[monkey] {
+            unknown[monkey] = unknown[a] || unknown[b];
+        }
+    }
+
+    let Job::Op(_, a, b) = input.jobs[input.root] else {
+        return None;
+    };
+    let (mut monkey, mut target) = match (unknown[a], unknown[b]) {
+        (true, false) => (a, values[b]),
+        (false, true) => (b, values[a]),
+        _ => return None,
+    };
+    while monkey != input.humn {
+        let Job::Op(op, a, b) = input.jobs[monkey] else {
+            unreachable!("only humn is unknown on its own");
+        };
+        target = match (unknown[a], unknown[b]) {
+            // target = x op known
+            (true, false) => {
+                let known = values[b];
+                monkey = a;
+                match op {
+                    Op::Add => target.checked_sub(known)?,
+                    Op::Sub => target.checked_add(known)?,
+                    Op::Mul => exact_div(target, known)?,
+                    Op::Div => target.checked_mul(known)?,
+                }
+            }
+            // target = known op x
+            (false, true) => {
+                let known = values[a];
+                monkey = b;
+                match op {
+                    Op::Add => target.checked_sub(known)?,
+                    Op::Sub => known.checked_sub(target)?,
+                    Op::Mul => exact_div(target, known)?,
+                    Op::Div => exact_div(known, target)?,
+                }
+            }
+            _ => return None,
+        };
+    }
+    Some(target)
+}
+
+fn part1(input: &Input) -> i64 {
+    evaluate(input)[input.root]
+}
+
+fn part2(input: &Input) -> i64 {
+    solve(input).expect("no whole number for humn makes root's operands equal")
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+    #[test]
+    fn example_part1() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "152");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "301");
+    }
+
+    #[test]
+    fn cycles_are_rejected() {
+        let s = "root: humn + a\na: b * 2\nb: a - 1\nhumn: 5";
+        let err = Day21::parse(s).unwrap_err().locate(s);
+        assert_eq!((err.line(), err.column()), (3, 4));
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day21>();
+    }
+}
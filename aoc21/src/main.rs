@@ -1,3 +1,5 @@
+use aoc21::Day21;
+
 fn main() {
-    println!("Hello, world!");
+    aoc_common::main::<Day21>();
 }
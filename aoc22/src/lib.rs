@@ -0,0 +1,409 @@
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::{parse, ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Tile {
+    /// Off the board.
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Move {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+/// Right, down, left and up, as `(row, column)` steps, indexed by facing.
+const FACINGS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+#[derive(Debug)]
+pub struct Input {
+    board: Grid<Tile>,
+    path: Vec<Move>,
+}
+
+fn parse_board(s: &str) -> Result<Grid<Tile>, ParseError> {
+    let width = s
+        .lines()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut board = Grid::from_elem(s.lines().count(), width, Tile::Void);
+    for (row, line) in s.lines().enumerate() {
+        for (col, (i, c)) in line.char_indices().enumerate() {
+            board[(row, col)] = match c {
+                ' ' => Tile::Void,
+                '.' => Tile::Open,
+                '#' => Tile::Wall,
+                _ => {
+                    return Err(ParseError::new(
+                        "a tile ` `, `.` or `#`",
+                        &line[i..i + c.len_utf8()],
+                    ))
+                }
+            };
+        }
+    }
+    Ok(board)
+}
+
+fn parse_path(mut s: &str) -> Result<Vec<Move>, ParseError> {
+    let mut path = Vec::new();
+    while let Some(c) = s.chars().next() {
+        let len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        if len > 0 {
+            path.push(Move::Forward(parse::number(&s[..len])?));
+            s = &s[len..];
+            continue;
+        }
+        path.push(match c {
+            'L' => Move::Left,
+            'R' => Move::Right,
+            _ => {
+                return Err(ParseError::new(
+                    "a number or a turn `L` or `R`",
+                    &s[..c.len_utf8()],
+                ))
+            }
+        });
+        s = &s[c.len_utf8()..];
+    }
+    Ok(path)
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (board, path) = parse::split_once(s, "\n\n")?;
+        let board = parse_board(board)?;
+        if board.find(&Tile::Open).is_none() {
+            return Err(ParseError::new("a board with an open tile", s));
+        }
+
+        Ok(Self {
+            board,
+            path: parse_path(path.trim_end())?,
+        })
+    }
+}
+
+/// The tile one step from `pos` in `facing`, if that is on the board.
+fn step(board: &Grid<Tile>, pos: Pos, facing: usize) -> Option<Pos> {
+    board
+        .step(pos, FACINGS[facing])
+        .filter(|&next| board[next] != Tile::Void)
+}
+
+/// Follows `path` from the leftmost open tile of the top row, facing right,
+/// and returns the final password. Stepping off the board goes wherever
+/// `wrap` says, with whatever facing it says.
+fn walk(board: &Grid<Tile>, path: &[Move], wrap: impl Fn(Pos, usize) -> (Pos, usize)) -> usize {
+    let mut pos = board.find(&Tile::Open).unwrap();
+    let mut facing = 0;
+    for &m in path {
+        match m {
+            Move::Left => facing = (facing + 3) % 4,
+            Move::Right => facing = (facing + 1) % 4,
+            Move::Forward(steps) => {
+                for _ in 0..steps {
+                    let (next, next_facing) = match step(board, pos, facing) {
+                        Some(next) => (next, facing),
+                        None => wrap(pos, facing),
+                    };
+                    if board[next] == Tile::Wall {
+                        break;
+                    }
+                    (pos, facing) = (next, next_facing);
+                }
+            }
+        }
+    }
+    1000 * (pos.0 + 1) + 4 * (pos.1 + 1) + facing
+}
+
+/// Wraps around to the far side of the board's row or column.
+fn wrap_flat(board: &Grid<Tile>, pos: Pos, facing: usize) -> (Pos, usize) {
+    let back = (facing + 2) % 4;
+    let mut pos = pos;
+    while let Some(next) = step(board, pos, back) {
+        pos = next;
+    }
+    (pos, facing)
+}
+
+type Vector = [i64; 3];
+
+fn scale(v: Vector, k: i64) -> Vector {
+    v.map(|x| x * k)
+}
+
+fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// A face of the cube: where it is in the net, and which way it points
+/// once folded.
+#[derive(Copy, Clone, Debug)]
+struct Face {
+    /// Its top left tile on the board.
+    corner: Pos,
+    /// Outwards, away from the centre of the cube.
+    normal: Vector,
+    /// Along the face's rows and columns on the board.
+    right: Vector,
+    down: Vector,
+}
+
+impl Face {
+    /// The direction of `facing` on the board, on the cube.
+    fn direction(&self, facing: usize) -> Vector {
+        match facing {
+            0 => self.right,
+            1 => self.down,
+            2 => scale(self.right, -1),
+            _ => scale(self.down, -1),
+        }
+    }
+
+    /// The face across the edge at `facing`, as folded: it points where
+    /// this face's edge was heading, and walking onto it goes inwards.
+    fn fold(&self, facing: usize, corner: Pos) -> Face {
+        let normal = self.direction(facing);
+        let inwards = scale(self.normal, -1);
+        let (right, down) = match facing {
+            0 => (inwards, self.down),
+            1 => (self.right, inwards),
+            2 => (self.normal, self.down),
+            _ => (self.right, self.normal),
+        };
+        Face {
+            corner,
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+/// The board folded up into a cube.
+///
+/// Every face gets its orientation in space by folding outwards from the
+/// first one along the net. With the cube centred on the origin and twice
+/// the face size across, so that every tile has a whole-numbered centre,
+/// stepping off an edge is stepping in space, and the tile stepped onto
+/// belongs to whichever face points that way.
+struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    /// Folds `board` into a cube, if it is the net of one.
+    fn fold(board: &Grid<Tile>) -> Option<Self> {
+        let tiles = board.iter().filter(|&&tile| tile != Tile::Void).count();
+        let size = (1..).find(|size| 6 * size * size >= tiles)?;
+        if 6 * size * size != tiles {
+            return None;
+        }
+
+        let first = board
+            .indexed_iter()
+            .find(|(_, &tile)| tile != Tile::Void)?
+            .0;
+        let mut faces = vec![Face {
+            corner: first,
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        }];
+        let mut i = 0;
+        while i < faces.len() {
+            let face = faces[i];
+            i += 1;
+            for (facing, (dr, dc)) in FACINGS.into_iter().enumerate() {
+                let Some(corner) =
+                    board.step(face.corner, (dr * size as isize, dc * size as isize))
+                else {
+                    continue;
+                };
+                if board[corner] != Tile::Void && faces.iter().all(|face| face.corner != corner) {
+                    faces.push(face.fold(facing, corner));
+                }
+            }
+        }
+
+        // Six faces that overlap once folded are not a cube either.
+        let distinct =
+            (0..faces.len()).all(|i| faces[..i].iter().all(|face| face.normal != faces[i].normal));
+        (faces.len() == 6 && distinct).then_some(Self { size, faces })
+    }
+
+    fn face(&self, pos: Pos) -> &Face {
+        let corner = (pos.0 / self.size * self.size, pos.1 / self.size * self.size);
+        self.faces
+            .iter()
+            .find(|face| face.corner == corner)
+            .unwrap()
+    }
+
+    /// The centre of `pos` in space.
+    fn locate(&self, face: &Face, pos: Pos) -> Vector {
+        let size = self.size as i64;
+        let (row, col) = (
+            (pos.0 - face.corner.0) as i64,
+            (pos.1 - face.corner.1) as i64,
+        );
+        let centre = scale(face.normal, size);
+        let across = scale(face.right, 2 * col + 1 - size);
+        let along = scale(face.down, 2 * row + 1 - size);
+        add(add(centre, across), along)
+    }
+
+    /// Steps off the edge of `pos`'s face at `facing`, onto the next face
+    /// around the cube.
+    fn wrap(&self, pos: Pos, facing: usize) -> (Pos, usize) {
+        let from = self.face(pos);
+        let heading = from.direction(facing);
+        let point = add(add(self.locate(from, pos), heading), scale(from.normal, -1));
+
+        let to = self
+            .faces
+            .iter()
+            .find(|face| face.normal == heading)
+            .unwrap();
+        let size = self.size as i64;
+        let offset = add(point, scale(to.normal, -size));
+        let col = (dot(offset, to.right) + size - 1) / 2;
+        let row = (dot(offset, to.down) + size - 1) / 2;
+        let inwards = scale(from.normal, -1);
+        let facing = (0..4).find(|&f| to.direction(f) == inwards).unwrap();
+        (
+            (to.corner.0 + row as usize, to.corner.1 + col as usize),
+            facing,
+        )
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    walk(&input.board, &input.path, |pos, facing| {
+        wrap_flat(&input.board, pos, facing)
+    })
+}
+
+fn part2(input: &Input) -> usize {
+    let cube = Cube::fold(&input.board).expect("the board does not fold into a cube");
+    walk(&input.board, &input.path, |pos, facing| {
+        cube.wrap(pos, facing)
+    })
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Not a `\` continuation, which would eat the leading spaces of the net.
+    const EXAMPLE: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+    #[test]
+    fn example_part1() {
+        let input = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "6032");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "5031");
+    }
+
+    /// Stepping off any edge of the cube and turning around leads straight
+    /// back, whatever the shape of the net.
+    #[test]
+    fn wrapping_is_reversible() {
+        let nets = [
+            "  #\n###\n  ##",
+            " ##\n #\n##\n#",
+            "#\n###\n #\n #",
+            "##\n ###\n   #",
+        ];
+        for net in nets {
+            let size = 3;
+            let board: String = net
+                .lines()
+                .flat_map(|line| {
+                    let row: String = line
+                        .chars()
+                        .flat_map(|c| std::iter::repeat_n(if c == '#' { '.' } else { ' ' }, size))
+                        .collect();
+                    std::iter::repeat_n(row + "\n", size)
+                })
+                .collect();
+            let board = parse_board(&board).unwrap();
+            let cube = Cube::fold(&board).unwrap_or_else(|| panic!("{:?} does not fold", net));
+            for (pos, &tile) in board.indexed_iter() {
+                for facing in 0..4 {
+                    if tile == Tile::Void || step(&board, pos, facing).is_some() {
+                        continue;
+                    }
+                    let (next, next_facing) = cube.wrap(pos, facing);
+                    assert_eq!(
+                        cube.wrap(next, (next_facing + 2) % 4),
+                        (pos, (facing + 2) % 4),
+                        "{:?} from {:?} facing {}",
+                        net,
+                        pos,
+                        facing
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day22>();
+    }
+}
@@ -1,3 +1,5 @@
+use aoc22::Day22;
+
 fn main() {
-    println!("Hello, world!");
+    aoc_common::main::<Day22>();
 }
@@ -0,0 +1,230 @@
+use aoc_common::grid::Grid;
+use aoc_common::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::str::FromStr;
+
+/// An elf's position as `(row, column)`, which can go anywhere as the elves
+/// spread out.
+type Elf = (i32, i32);
+
+/// A multiply-and-rotate hash, plenty for small integer keys. Looking up
+/// neighbours is what every round spends its time on, and the default hash
+/// is several times slower.
+#[derive(Default)]
+struct ElfHasher(u64);
+
+impl Hasher for ElfHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.write_u64(byte as u64);
+        }
+    }
+
+    fn write_i32(&mut self, n: i32) {
+        self.write_u64(n as u32 as u64);
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = (self.0.rotate_left(5) ^ n).wrapping_mul(0x517c_c1b7_2722_0a95);
+    }
+}
+
+type Hash = BuildHasherDefault<ElfHasher>;
+
+#[derive(Debug)]
+pub struct Input {
+    elves: HashSet<Elf, Hash>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(s, "ground `.` or an elf `#`", |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        })?;
+        let elves = grid
+            .indexed_iter()
+            .filter(|(_, &elf)| elf)
+            .map(|((row, col), _)| (row as i32, col as i32))
+            .collect();
+
+        Ok(Self { elves })
+    }
+}
+
+/// The eight neighbours, clockwise from north, as `(row, column)` steps.
+const AROUND: [(i32, i32); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// North, south, west and east: the step, and the bits in an elf's
+/// neighbourhood (see [`AROUND`]) that have to be free to take it.
+const PROPOSALS: [((i32, i32), u8); 4] = [
+    ((-1, 0), 0b1000_0011),
+    ((1, 0), 0b0011_1000),
+    ((0, -1), 0b1110_0000),
+    ((0, 1), 0b0000_1110),
+];
+
+struct Grove {
+    /// Every elf, for going through them in order.
+    elves: Vec<Elf>,
+    /// Every elf, for looking them up by position.
+    occupied: HashSet<Elf, Hash>,
+    /// Where elves want to go, and the index of the elf that wants to go
+    /// there. Only elves facing each other across a tile can pick the same
+    /// one, so a second proposal means nobody goes.
+    proposals: HashMap<Elf, Option<usize>, Hash>,
+    rounds: usize,
+}
+
+impl Grove {
+    fn new(elves: &HashSet<Elf, Hash>) -> Self {
+        Self {
+            elves: elves.iter().copied().collect(),
+            occupied: elves.clone(),
+            proposals: HashMap::default(),
+            rounds: 0,
+        }
+    }
+
+    /// Plays a round, and returns whether any elf moved.
+    fn round(&mut self) -> bool {
+        for (i, &elf) in self.elves.iter().enumerate() {
+            let neighbours = AROUND
+                .iter()
+                .enumerate()
+                .filter(|(_, (dr, dc))| self.occupied.contains(&(elf.0 + dr, elf.1 + dc)))
+                .fold(0u8, |bits, (bit, _)| bits | 1 << bit);
+            if neighbours == 0 {
+                continue;
+            }
+            let proposal = (0..PROPOSALS.len())
+                .map(|k| PROPOSALS[(self.rounds + k) % PROPOSALS.len()])
+                .find(|&(_, free)| neighbours & free == 0);
+            if let Some(((dr, dc), _)) = proposal {
+                self.proposals
+                    .entry((elf.0 + dr, elf.1 + dc))
+                    .and_modify(|elf| *elf = None)
+                    .or_insert(Some(i));
+            }
+        }
+        self.rounds += 1;
+
+        let mut moved = false;
+        for (to, i) in self.proposals.drain() {
+            if let Some(i) = i {
+                self.occupied.remove(&self.elves[i]);
+                self.occupied.insert(to);
+                self.elves[i] = to;
+                moved = true;
+            }
+        }
+        moved
+    }
+
+    /// The number of empty tiles in the smallest rectangle holding every
+    /// elf, which for no elves at all holds nothing.
+    fn empty_ground(&self) -> usize {
+        if self.elves.is_empty() {
+            return 0;
+        }
+        let (mut top, mut left) = (i32::MAX, i32::MAX);
+        let (mut bottom, mut right) = (i32::MIN, i32::MIN);
+        for &(row, col) in &self.elves {
+            top = top.min(row);
+            bottom = bottom.max(row);
+            left = left.min(col);
+            right = right.max(col);
+        }
+        let area = (bottom - top + 1) as usize * (right - left + 1) as usize;
+        area - self.elves.len()
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    let mut grove = Grove::new(&input.elves);
+    for _ in 0..10 {
+        grove.round();
+    }
+    grove.empty_ground()
+}
+
+fn part2(input: &Input) -> usize {
+    let mut grove = Grove::new(&input.elves);
+    while grove.round() {}
+    grove.rounds
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+    #[test]
+    fn example_part1() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "110");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "20");
+    }
+
+    #[test]
+    fn no_elves() {
+        let input = Day23::parse("...\n...").unwrap();
+        assert_eq!(part1(&input).to_string(), "0");
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day23>();
+    }
+}
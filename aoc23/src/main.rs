@@ -1,3 +1,5 @@
+use aoc23::Day23;
+
 fn main() {
-    println!("Hello, world!");
+    aoc_common::main::<Day23>();
 }
@@ -0,0 +1,210 @@
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::{search, ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Input {
+    /// The valley as it is at minute 0, walls included.
+    valley: Grid<char>,
+    start: Pos,
+    goal: Pos,
+    /// How many minutes until every blizzard is back where it started.
+    period: usize,
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valley = Grid::parse(s, "a wall `#`, ground `.` or a blizzard", |c| {
+            matches!(c, '#' | '.' | '>' | '<' | '^' | 'v').then_some(c)
+        })?;
+        if valley.width() < 3 || valley.height() < 3 {
+            return Err(ParseError::end("a valley inside walls", s));
+        }
+        let (width, height) = (valley.width(), valley.height());
+        let gap = |row: usize, line: &str| {
+            let cells = valley.row(row);
+            match cells.iter().position(|&c| c == '.') {
+                Some(col)
+                    if cells.iter().filter(|&&c| c == '.').count() == 1
+                        && (1..width - 1).contains(&col) =>
+                {
+                    Ok((row, col))
+                }
+                _ => Err(ParseError::new("a wall with a single gap", line)),
+            }
+        };
+        let lines: Vec<&str> = s.lines().collect();
+        let start = gap(0, lines[0])?;
+        let goal = gap(height - 1, lines[height - 1])?;
+        // Blizzards wrap around inside the walls, so there can be no other
+        // way out. Every cell Grid::parse accepts is a single byte.
+        for (pos, &c) in valley.indexed_iter() {
+            let border = pos.0 == 0 || pos.0 == height - 1 || pos.1 == 0 || pos.1 == width - 1;
+            if border && c != '#' && pos != start && pos != goal {
+                return Err(ParseError::new(
+                    "a wall `#`",
+                    &lines[pos.0][pos.1..pos.1 + 1],
+                ));
+            }
+        }
+
+        let (width, height) = (width - 2, height - 2);
+        Ok(Self {
+            valley,
+            start,
+            goal,
+            period: width / gcd(width, height) * height,
+        })
+    }
+}
+
+impl Input {
+    /// Whether `pos` is free of walls and blizzards at `minute`.
+    fn is_clear(&self, pos: Pos, minute: usize) -> bool {
+        if self.valley[pos] == '#' {
+            return false;
+        }
+        if pos == self.start || pos == self.goal {
+            return true;
+        }
+
+        // Look back along each row and column for where a blizzard heading
+        // for `pos` would have started.
+        let (width, height) = (self.valley.width() - 2, self.valley.height() - 2);
+        let (row, col) = (pos.0 - 1, pos.1 - 1);
+        let (dx, dy) = (minute % width, minute % height);
+        let inner = |row: usize, col: usize| self.valley[(row + 1, col + 1)];
+        inner(row, (col + width - dx) % width) != '>'
+            && inner(row, (col + dx) % width) != '<'
+            && inner((row + height - dy) % height, col) != 'v'
+            && inner((row + dy) % height, col) != '^'
+    }
+
+    /// The fewest minutes from `from` to `to`, setting off at `minute`.
+    ///
+    /// The search runs over positions in time, which repeats with the
+    /// blizzards, so a state is a position and a minute within the
+    /// blizzards' period.
+    fn cross(&self, from: Pos, to: Pos, minute: usize) -> Option<usize> {
+        let neighbours = |&(pos, minute): &(Pos, usize)| {
+            let next = (minute + 1) % self.period;
+            self.valley
+                .neighbours4(pos)
+                .chain([pos])
+                .filter(move |&pos| self.is_clear(pos, next))
+                .map(move |pos| (pos, next))
+        };
+        let path = search::bfs((from, minute % self.period), neighbours, |&(pos, _)| {
+            pos == to
+        })?;
+        Some(path.cost)
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    input
+        .cross(input.start, input.goal, 0)
+        .expect("there is no way through the valley")
+}
+
+fn part2(input: &Input) -> usize {
+    let legs = [
+        (input.start, input.goal),
+        (input.goal, input.start),
+        (input.start, input.goal),
+    ];
+    legs.into_iter().fold(0, |minute, (from, to)| {
+        minute
+            + input
+                .cross(from, to, minute)
+                .expect("there is no way through the valley")
+    })
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn example_part1() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "18");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "54");
+    }
+
+    #[test]
+    fn blizzards_repeat() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(input.period, 12);
+        for row in 0..input.valley.height() {
+            for col in 0..input.valley.width() {
+                let pos = (row, col);
+                assert_eq!(input.is_clear(pos, 5), input.is_clear(pos, 17));
+            }
+        }
+        // The first two blizzards move right, one column a minute.
+        assert!(!input.is_clear((1, 3), 1));
+        assert!(input.is_clear((1, 1), 1));
+    }
+
+    #[test]
+    fn walls_are_closed() {
+        let s = "#.###\n.>..#\n###.#";
+        let err = Day24::parse(s).unwrap_err().locate(s);
+        assert_eq!(err.expected(), "a wall `#`");
+        assert_eq!((err.line(), err.column()), (2, 1));
+        let s = "#.###\n#>..>\n###.#";
+        let err = Day24::parse(s).unwrap_err().locate(s);
+        assert_eq!((err.line(), err.column()), (2, 5));
+        assert!(Day24::parse(".####\n#>..#\n###.#").is_err());
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day24>();
+    }
+}
@@ -1,3 +1,5 @@
+use aoc24::Day24;
+
 fn main() {
-    println!("Hello, world!");
+    aoc_common::main::<Day24>();
 }
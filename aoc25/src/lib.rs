@@ -0,0 +1,275 @@
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+/// A number in SNAFU, balanced base 5, where the digits `=`, `-`, `0`, `1`
+/// and `2` stand for -2 to 2.
+///
+/// Numbers are kept as their digits, so adding them never overflows; only
+/// converting to a machine integer can.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Snafu {
+    /// Least significant first, with no trailing zeros, so zero has none.
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    fn normalize(mut self) -> Self {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        self
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::end("a SNAFU number", s));
+        }
+        let digits = s
+            .char_indices()
+            .rev()
+            .map(|(i, c)| match c {
+                '=' => Ok(-2),
+                '-' => Ok(-1),
+                '0' => Ok(0),
+                '1' => Ok(1),
+                '2' => Ok(2),
+                _ => Err(ParseError::new(
+                    "a SNAFU digit `=`, `-`, `0`, `1` or `2`",
+                    &s[i..i + c.len_utf8()],
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { digits }.normalize())
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+        let s: String = self
+            .digits
+            .iter()
+            .rev()
+            .map(|digit| match digit {
+                -2 => '=',
+                -1 => '-',
+                0 => '0',
+                1 => '1',
+                _ => '2',
+            })
+            .collect();
+        f.pad(&s)
+    }
+}
+
+impl Add for &Snafu {
+    type Output = Snafu;
+
+    /// Adds digit by digit, carrying whatever falls outside -2 to 2.
+    fn add(self, other: &Snafu) -> Snafu {
+        let len = self.digits.len().max(other.digits.len());
+        let mut digits = Vec::with_capacity(len + 1);
+        let mut carry = 0;
+        for i in 0..len {
+            let sum = self.digits.get(i).unwrap_or(&0) + other.digits.get(i).unwrap_or(&0) + carry;
+            let digit = (sum + 2).rem_euclid(5) - 2;
+            digits.push(digit);
+            carry = (sum - digit) / 5;
+        }
+        digits.push(carry);
+        Snafu { digits }.normalize()
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: Snafu) -> Snafu {
+        &self + &other
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |sum, n| &sum + &n)
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |sum, n| &sum + n)
+    }
+}
+
+/// A SNAFU number too large for the integer type it was converted to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OutOfRange;
+
+impl Display for OutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SNAFU number out of range")
+    }
+}
+
+impl std::error::Error for OutOfRange {}
+
+macro_rules! convert {
+    ($($int:ty),*) => {$(
+        impl From<$int> for Snafu {
+            fn from(mut n: $int) -> Self {
+                let mut digits = Vec::new();
+                while n != 0 {
+                    // Dividing before adjusting keeps clear of overflow at
+                    // the ends of the range.
+                    let (mut quotient, rest) = (n.div_euclid(5), n.rem_euclid(5));
+                    let digit = if rest > 2 {
+                        quotient += 1;
+                        rest - 5
+                    } else {
+                        rest
+                    };
+                    digits.push(digit as i8);
+                    n = quotient;
+                }
+                Self { digits }
+            }
+        }
+
+        impl TryFrom<&Snafu> for $int {
+            type Error = OutOfRange;
+
+            fn try_from(n: &Snafu) -> Result<Self, OutOfRange> {
+                n.digits
+                    .iter()
+                    .rev()
+                    // As `value * 5 + digit`, but without leaving the
+                    // range on the way to a value at its very end.
+                    .try_fold(0 as $int, |value, &digit| {
+                        let rest = value.checked_add(digit as $int)?;
+                        value.checked_mul(4)?.checked_add(rest)
+                    })
+                    .ok_or(OutOfRange)
+            }
+        }
+    )*};
+}
+
+convert!(i64, i128);
+
+#[derive(Debug)]
+pub struct Input {
+    numbers: Vec<Snafu>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = s.lines().map(str::parse).collect::<Result<_, _>>()?;
+        Ok(Self { numbers })
+    }
+}
+
+fn part1(input: &Input) -> Snafu {
+    input.numbers.iter().sum()
+}
+
+/// The last day has only the one puzzle.
+fn part2(_input: &Input) -> &'static str {
+    "-"
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        s.parse()
+    }
+
+    fn part1(input: &Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+    #[test]
+    fn example_part1() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "2=-1=0");
+    }
+
+    #[test]
+    fn conversions() {
+        let table: [(i64, &str); 10] = [
+            (0, "0"),
+            (1, "1"),
+            (3, "1="),
+            (8, "2="),
+            (10, "20"),
+            (15, "1=0"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+            (-3, "-2"),
+        ];
+        for (n, s) in table {
+            let snafu: Snafu = s.parse().unwrap();
+            assert_eq!(snafu, Snafu::from(n));
+            assert_eq!(snafu.to_string(), s);
+            assert_eq!(i64::try_from(&snafu), Ok(n));
+            assert_eq!(i128::try_from(&snafu), Ok(n as i128));
+        }
+        assert_eq!("00-".parse::<Snafu>().unwrap().to_string(), "-");
+    }
+
+    #[test]
+    fn sums_past_i64() {
+        let max = Snafu::from(i64::MAX);
+        let sum = &max + &max;
+        assert_eq!(i64::try_from(&sum), Err(OutOfRange));
+        assert_eq!(i128::try_from(&sum), Ok(2 * i64::MAX as i128));
+        assert_eq!(Snafu::from(i128::MIN).to_string().len(), 56);
+        assert_eq!(i128::try_from(&Snafu::from(i128::MIN)), Ok(i128::MIN));
+    }
+
+    #[test]
+    fn answers() {
+        aoc_common::answers::check::<Day25>();
+    }
+}
@@ -1,3 +1,5 @@
+use aoc25::Day25;
+
 fn main() {
-    println!("Hello, world!");
+    aoc_common::main::<Day25>();
 }